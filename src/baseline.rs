@@ -0,0 +1,258 @@
+use crate::constants::{BASELINE_FILE_NAME, BASELINE_VERSION};
+use crate::error::LintError;
+use crate::project_config::ProjectConfig;
+use crate::report::Report;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// one accepted problem, the path is relative to the config file directory
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub path: String,
+    pub rule_id: String,
+    pub fingerprint: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BaselineFile {
+    version: u32,
+    entries: BTreeSet<BaselineEntry>,
+}
+
+// problems accepted by `lint_master baseline`, check leaves them out of the report
+pub struct Baseline {
+    entries: BTreeSet<BaselineEntry>,
+}
+
+impl Baseline {
+    pub fn default_path(project: &ProjectConfig) -> PathBuf {
+        project.root.join(BASELINE_FILE_NAME)
+    }
+
+    // None when there is no baseline file yet
+    pub fn load(path: &Path) -> Result<Option<Baseline>, LintError> {
+        let display = path.display().to_string();
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(LintError::io(&display, e)),
+        };
+        let file: BaselineFile = serde_json::from_str(&contents)
+            .map_err(|e| LintError::Config(format!("invalid {}: {}", display, e)))?;
+        if file.version != BASELINE_VERSION {
+            return Err(LintError::Config(format!(
+                "{} has version {}, expected {}",
+                display, file.version, BASELINE_VERSION
+            )));
+        }
+
+        Ok(Some(Baseline {
+            entries: file.entries,
+        }))
+    }
+
+    // every problem of the report, plus the entries of `previous` for files and rules
+    // the report did not check
+    pub fn from_report(
+        report: &Report,
+        project: &ProjectConfig,
+        previous: Option<Baseline>,
+    ) -> Baseline {
+        let checked = Self::checked(report, project);
+        let mut entries: BTreeSet<BaselineEntry> = previous
+            .map(|previous| previous.entries)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|entry| !checked.contains(&(entry.path.clone(), entry.rule_id.clone())))
+            .collect();
+
+        for file in &report.files {
+            let path = Self::relative_path(project, &file.path);
+            for diagnostic in file.diagnostics() {
+                entries.insert(BaselineEntry {
+                    path: path.clone(),
+                    rule_id: diagnostic.rule_id.clone(),
+                    fingerprint: diagnostic.fingerprint.clone(),
+                });
+            }
+        }
+
+        Baseline { entries }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn save(self, path: &Path) -> Result<(), LintError> {
+        let file = BaselineFile {
+            version: BASELINE_VERSION,
+            entries: self.entries,
+        };
+        // one entry per line keeps the committed file reviewable
+        let contents = serde_json::to_string_pretty(&file).expect("baseline entries serialize");
+        fs::write(path, contents + "\n")
+            .map_err(|e| LintError::write(&path.display().to_string(), e))
+    }
+
+    // drops the recorded problems from the report and lists the entries not found anymore
+    pub fn apply(&self, report: &mut Report, project: &ProjectConfig) {
+        let checked = Self::checked(report, project);
+        let mut remaining: HashSet<&BaselineEntry> = self
+            .entries
+            .iter()
+            .filter(|entry| checked.contains(&(entry.path.clone(), entry.rule_id.clone())))
+            .collect();
+
+        for file in report.files.iter_mut() {
+            let path = Self::relative_path(project, &file.path);
+            for check in file.checks.iter_mut() {
+                let entry = |fingerprint: &str| BaselineEntry {
+                    path: path.clone(),
+                    rule_id: check.rule_id.clone(),
+                    fingerprint: fingerprint.to_string(),
+                };
+                let before = check.diagnostics.len();
+                check
+                    .diagnostics
+                    .retain(|diagnostic| !self.entries.contains(&entry(&diagnostic.fingerprint)));
+                report.baselined += before - check.diagnostics.len();
+                // a problem left out by a suppression or `--changed-lines` is still there
+                for fingerprint in &check.found {
+                    remaining.remove(&entry(fingerprint));
+                }
+            }
+        }
+
+        let mut fixed: Vec<BaselineEntry> = remaining.into_iter().cloned().collect();
+        fixed.sort();
+        report.fixed = fixed;
+    }
+
+    // (path, rule id) pairs the report ran
+    fn checked(report: &Report, project: &ProjectConfig) -> HashSet<(String, String)> {
+        report
+            .files
+            .iter()
+            .flat_map(|file| {
+                let path = Self::relative_path(project, &file.path);
+                file.checks
+                    .iter()
+                    .map(move |check| (path.clone(), check.rule_id.clone()))
+            })
+            .collect()
+    }
+
+    fn relative_path(project: &ProjectConfig, file_path: &str) -> String {
+        project
+            .relative_path(file_path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::Diagnostic;
+    use crate::report::{FileReport, RuleCheck};
+
+    fn project() -> ProjectConfig {
+        ProjectConfig {
+            root: PathBuf::from("/lint-master-baseline-root"),
+            ..ProjectConfig::default()
+        }
+    }
+
+    fn diagnostic(path: &str, fingerprint: &str) -> Diagnostic {
+        let mut diagnostic = Diagnostic::new("todo", path, "has unresolved marker".to_string());
+        diagnostic.fingerprint = fingerprint.to_string();
+        diagnostic
+    }
+
+    // a report of the todo rule, `found` also holds the fingerprints of `hidden`
+    fn report(path: &str, fingerprints: &[&str], hidden: &[&str]) -> Report {
+        let diagnostics: Vec<Diagnostic> = fingerprints
+            .iter()
+            .map(|fingerprint| diagnostic(path, fingerprint))
+            .collect();
+        let found = fingerprints
+            .iter()
+            .chain(hidden)
+            .map(|fingerprint| fingerprint.to_string())
+            .collect();
+        Report {
+            files: vec![FileReport {
+                path: path.to_string(),
+                checks: vec![RuleCheck {
+                    rule_id: "todo".to_string(),
+                    label: "TODO".to_string(),
+                    diagnostics,
+                    found,
+                }],
+            }],
+            ..Report::default()
+        }
+    }
+
+    fn entry(path: &str, fingerprint: &str) -> BaselineEntry {
+        BaselineEntry {
+            path: path.to_string(),
+            rule_id: "todo".to_string(),
+            fingerprint: fingerprint.to_string(),
+        }
+    }
+
+    #[test]
+    fn apply_drops_recorded_problems_and_lists_fixed_entries() {
+        let baseline =
+            Baseline::from_report(&report("a.ts", &["1", "2", "3"], &[]), &project(), None);
+        assert_eq!(baseline.len(), 3);
+
+        // `2` is fixed, `3` is only hidden by a suppression and `4` is new
+        let mut report = report("a.ts", &["1", "4"], &["3"]);
+        baseline.apply(&mut report, &project());
+
+        let left: Vec<&str> = report
+            .diagnostics()
+            .map(|diagnostic| diagnostic.fingerprint.as_str())
+            .collect();
+        assert_eq!(left, ["4"]);
+        assert_eq!(report.baselined, 1);
+        assert_eq!(report.fixed, [entry("a.ts", "2")]);
+    }
+
+    #[test]
+    fn apply_ignores_entries_of_unchecked_files() {
+        let baseline = Baseline::from_report(&report("b.ts", &["1"], &[]), &project(), None);
+        let mut report = report("a.ts", &["1"], &[]);
+        baseline.apply(&mut report, &project());
+
+        assert_eq!(report.baselined, 0);
+        assert!(report.fixed.is_empty());
+    }
+
+    #[test]
+    fn from_report_keeps_entries_of_unchecked_files() {
+        let previous = Baseline::from_report(&report("b.ts", &["1"], &[]), &project(), None);
+        let previous = Baseline {
+            entries: previous
+                .entries
+                .into_iter()
+                .chain([entry("a.ts", "old")])
+                .collect(),
+        };
+        let baseline =
+            Baseline::from_report(&report("a.ts", &["2"], &[]), &project(), Some(previous));
+
+        let entries: Vec<BaselineEntry> = baseline.entries.into_iter().collect();
+        assert_eq!(entries, [entry("a.ts", "2"), entry("b.ts", "1")]);
+    }
+}
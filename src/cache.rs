@@ -0,0 +1,185 @@
+use crate::constants::{CACHE_DIR, CACHE_FILE_NAME};
+use crate::diagnostic::Diagnostic;
+use crate::error::LintError;
+use crate::git::Git;
+use crate::project_config::RuleOptions;
+use crate::rules::{Rule, SourceFile};
+use crate::utils::to_hex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    key: String,
+    diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct CacheFile {
+    version: String,
+    // file path -> rule id -> last result
+    entries: BTreeMap<String, BTreeMap<String, CacheEntry>>,
+}
+
+// rule results of previous runs, stored in `.git/lint-master/cache.json`
+pub struct Cache {
+    path: PathBuf,
+    file: CacheFile,
+}
+
+impl Cache {
+    // None outside of a git repository
+    pub fn open() -> Result<Option<Cache>, LintError> {
+        let path = match Git::git_dir()? {
+            Some(git_dir) => git_dir.join(CACHE_DIR).join(CACHE_FILE_NAME),
+            None => return Ok(None),
+        };
+
+        // an unreadable cache or one written by another version starts over
+        let file = fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| serde_json::from_str::<CacheFile>(&contents).ok())
+            .filter(|file| file.version == env!("CARGO_PKG_VERSION"))
+            .unwrap_or_else(|| CacheFile {
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                entries: BTreeMap::new(),
+            });
+
+        Ok(Some(Cache { path, file }))
+    }
+
+    // hash of everything a rule result depends on
+    pub fn key(
+        rule: &dyn Rule,
+        options: &RuleOptions,
+        file: &SourceFile,
+    ) -> Result<String, LintError> {
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION"));
+        hasher.update([0]);
+        hasher.update(rule.id());
+        hasher.update([0]);
+        hasher.update(toml::Value::Table(options.table().clone()).to_string());
+        hasher.update([0]);
+        hasher.update([file.is_added as u8]);
+        hasher.update(&file.contents);
+        for dependency in rule.dependencies(options)? {
+            hasher.update([0]);
+            hasher.update(dependency.to_string_lossy().as_bytes());
+            hasher.update([0]);
+            match fs::read(&dependency) {
+                Ok(contents) => hasher.update(contents),
+                Err(_) => hasher.update([1]),
+            }
+        }
+
+        Ok(to_hex(&hasher.finalize()))
+    }
+
+    pub fn get(&self, path: &str, rule_id: &str, key: &str) -> Option<Vec<Diagnostic>> {
+        self.file
+            .entries
+            .get(path)
+            .and_then(|rules| rules.get(rule_id))
+            .filter(|entry| entry.key == key)
+            .map(|entry| entry.diagnostics.clone())
+    }
+
+    pub fn insert(&mut self, path: &str, rule_id: &str, key: String, diagnostics: Vec<Diagnostic>) {
+        self.file
+            .entries
+            .entry(path.to_string())
+            .or_default()
+            .insert(rule_id.to_string(), CacheEntry { key, diagnostics });
+    }
+
+    pub fn save(&self) -> Result<(), LintError> {
+        let display = self.path.display().to_string();
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| LintError::write(&display, e))?;
+        }
+        let contents = serde_json::to_string(&self.file).expect("cache entries serialize");
+        fs::write(&self.path, contents).map_err(|e| LintError::write(&display, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project_config::ProjectConfig;
+    use crate::rules::TsxColor;
+    use std::path::Path;
+
+    fn source(contents: &str, is_added: bool) -> SourceFile {
+        SourceFile {
+            path: "src/a.tsx".to_string(),
+            extension: "tsx".to_string(),
+            contents: contents.to_string(),
+            is_added,
+            staged: false,
+        }
+    }
+
+    fn options(root: &Path, toml: &str) -> RuleOptions {
+        let mut project: ProjectConfig = toml::from_str(toml).unwrap();
+        project.root = root.to_path_buf();
+        project.resolve("tsx-color", "src/a.tsx").options
+    }
+
+    #[test]
+    fn key_changes_with_everything_the_result_depends_on() {
+        let root = std::env::temp_dir().join(format!("lint-master-cache-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let theme = "[rules.tsx-color]\ntheme_file = \"theme.ts\"\n";
+        let key = |toml: &str, file: &SourceFile| {
+            Cache::key(&TsxColor {}, &options(&root, toml), file).unwrap()
+        };
+
+        let file = source("const a = '#1A2B3C';\n", false);
+        let missing = key(theme, &file);
+        assert_eq!(missing, key(theme, &file));
+        fs::write(root.join("theme.ts"), "export const theme = {};\n").unwrap();
+        let written = key(theme, &file);
+        fs::write(
+            root.join("theme.ts"),
+            "export const theme = { a: '#1A2B3C' };\n",
+        )
+        .unwrap();
+        let edited = key(theme, &file);
+        let keys = [
+            missing,
+            written,
+            edited.clone(),
+            key(
+                "[rules.tsx-color]\ntheme_file = \"theme.ts\"\ntheme_name = \"t\"\n",
+                &file,
+            ),
+            key(theme, &source("const a = '#1A2B3D';\n", false)),
+            key(theme, &source("const a = '#1A2B3C';\n", true)),
+        ];
+        assert_eq!(edited, key(theme, &file));
+        fs::remove_dir_all(&root).unwrap();
+
+        for (i, a) in keys.iter().enumerate() {
+            assert!(keys[i + 1..].iter().all(|b| a != b), "key {} repeats", i);
+        }
+    }
+
+    #[test]
+    fn get_matches_the_key() {
+        let mut cache = Cache {
+            path: PathBuf::new(),
+            file: CacheFile::default(),
+        };
+        let diagnostic = Diagnostic::new("todo", "a.ts", "TODO".to_string());
+        cache.insert("a.ts", "todo", "k1".to_string(), vec![diagnostic]);
+
+        assert_eq!(cache.get("a.ts", "todo", "k1").map(|d| d.len()), Some(1));
+        assert!(cache.get("a.ts", "todo", "k2").is_none());
+        assert!(cache.get("a.ts", "eslint", "k1").is_none());
+        assert!(cache.get("b.ts", "todo", "k1").is_none());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::constants::RE_DIFF_HUNK;
+use crate::diagnostic::Diagnostic;
+use crate::error::LintError;
+use crate::git::Git;
+use regex::Regex;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+// lines touched by a git diff, keyed by absolute path
+pub struct ChangedLines {
+    files: HashMap<PathBuf, Vec<(usize, usize)>>,
+}
+
+impl ChangedLines {
+    // the index in staged mode, the working tree otherwise, compared to `base` or HEAD
+    pub fn load(base: Option<&str>, staged: bool) -> Result<ChangedLines, LintError> {
+        let toplevel = Git::toplevel()?;
+        let toplevel = fs::canonicalize(&toplevel).unwrap_or(toplevel);
+        let diff = Git::diff(base.unwrap_or("HEAD"), staged)?;
+
+        let mut files: HashMap<PathBuf, Vec<(usize, usize)>> = Self::parse(&diff)
+            .into_iter()
+            .map(|(path, ranges)| (toplevel.join(path), ranges))
+            .collect();
+        // untracked files are not part of the diff but every line of them is new
+        if !staged {
+            for path in Git::untracked_files()? {
+                files.insert(toplevel.join(path), vec![(1, usize::MAX)]);
+            }
+        }
+
+        Ok(ChangedLines { files })
+    }
+
+    // inclusive line ranges added by every hunk, keyed by the path relative to the repository root
+    fn parse(diff: &str) -> HashMap<String, Vec<(usize, usize)>> {
+        let re = Regex::new(RE_DIFF_HUNK).unwrap();
+        let mut files: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
+        let mut current = None;
+
+        for line in diff.lines() {
+            if let Some(path) = line.strip_prefix("+++ ") {
+                let path = path.trim_matches('"');
+                current = path.strip_prefix("b/").map(str::to_string);
+                if let Some(path) = &current {
+                    files.entry(path.clone()).or_default();
+                }
+            } else if let (Some(path), Some(cap)) = (&current, re.captures(line)) {
+                let start: usize = cap[1].parse().unwrap();
+                let count: usize = cap.get(2).map_or(1, |n| n.as_str().parse().unwrap());
+                // a count of 0 only removes lines
+                if count > 0 {
+                    files
+                        .entry(path.clone())
+                        .or_default()
+                        .push((start, start + count - 1));
+                }
+            }
+        }
+
+        files
+    }
+
+    // keeps the diagnostics overlapping a changed line of `file_path`
+    pub fn retain(&self, file_path: &str, diagnostics: &mut Vec<Diagnostic>) {
+        let path = fs::canonicalize(file_path).unwrap_or_else(|_| {
+            env::current_dir()
+                .map(|cwd| cwd.join(file_path))
+                .unwrap_or_else(|_| PathBuf::from(file_path))
+        });
+        let ranges = self.files.get(&path).map(Vec::as_slice).unwrap_or(&[]);
+        diagnostics.retain(|diagnostic| {
+            ranges
+                .iter()
+                .any(|&(start, end)| diagnostic.start.line <= end && diagnostic.end.line >= start)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_zero_context_hunks() {
+        let diff = "diff --git a/src/a.ts b/src/a.ts\n\
+                    --- a/src/a.ts\n\
+                    +++ b/src/a.ts\n\
+                    @@ -3 +3 @@ const a = 1;\n\
+                    -const b = 1;\n\
+                    +const b = 2;\n\
+                    @@ -10,0 +11,3 @@\n\
+                    +x\n\
+                    +y\n\
+                    +z\n\
+                    @@ -20,2 +22,0 @@\n\
+                    -gone\n\
+                    -gone\n";
+        let files = ChangedLines::parse(diff);
+
+        assert_eq!(files["src/a.ts"], vec![(3, 3), (11, 13)]);
+    }
+
+    #[test]
+    fn parse_new_and_deleted_files() {
+        let diff = "--- /dev/null\n\
+                    +++ b/new.go\n\
+                    @@ -0,0 +1,2 @@\n\
+                    +package main\n\
+                    +\n\
+                    --- a/old.go\n\
+                    +++ /dev/null\n\
+                    @@ -1 +0,0 @@\n\
+                    -package main\n";
+        let files = ChangedLines::parse(diff);
+
+        assert_eq!(files["new.go"], vec![(1, 2)]);
+        assert_eq!(files.len(), 1);
+    }
+
+    #[test]
+    fn parse_files_with_only_removed_lines() {
+        let diff = "--- a/a.ts\n+++ b/a.ts\n@@ -4 +3,0 @@\n-const a = 1;\n";
+        let files = ChangedLines::parse(diff);
+
+        assert_eq!(files["a.ts"], Vec::<(usize, usize)>::new());
+    }
+}
@@ -1,343 +1,392 @@
-use crate::constants::{
-    LintResult, CONSOLE_LOG, FILE_LINE, NECESSARY_CONSOLE_LOGGING, RE_LINT_GO, RE_LINT_TS,
-    RE_MATCH_COLOR, RE_TSX_THEME_FILE, SVG_ATTRIBUTE_NAMES, TODO_IGNORE_SEARCH, TODO_SEARCH,
-    WELCOME,
-};
-use crate::draw_table::{DrawTable, GoTable, TsTable};
-use crate::utils::{convert_to_camel_case, get_extension};
-use colored::Colorize;
-use regex::Regex;
-use std::collections::HashSet;
-use std::error::Error;
+use crate::cache::Cache;
+use crate::changed_lines::ChangedLines;
+use crate::constants::UNUSED_SUPPRESSION;
+use crate::diagnostic::Diagnostic;
+use crate::error::LintError;
+use crate::file_discovery::FileDiscovery;
+use crate::git::Git;
+use crate::project_config::{ProjectConfig, ResolvedRule};
+use crate::report::{FileReport, Report, RuleCheck};
+use crate::rules::{Registry, Rule, SourceFile};
+use crate::suppression::Suppressions;
+use crate::utils::{get_extension, to_hex};
+use crate::Config;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::process::Command;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+// one rule to run against one file, the unit of work of the worker pool
+struct Task<'a> {
+    file: usize,
+    rule: &'a dyn Rule,
+    resolved: ResolvedRule,
+}
 
 pub struct CheckFile {}
 
 impl CheckFile {
-    pub fn run(file_paths: Vec<String>) -> Result<(), Box<dyn Error>> {
-        println!("{}", WELCOME.green().bold());
-        let mut check_errors: u16 = 0;
-        let diff_add_files: Vec<String> = Self::git_add_files();
-
-        for file_path in file_paths {
-            let reader = fs::read_to_string(&file_path)?;
-            // support extension name .js .ts .tsx .go
-            match get_extension(&file_path) {
-                Ok(Some(ext)) => match &ext[..] {
-                    "js" | "ts" | "tsx" => {
-                        let match_color_result = Self::match_tsx_color(&reader, &mut check_errors)?;
-
-                        let lint_ts_result = Self::lint_ts(&file_path, &mut check_errors);
-                        let match_svg_attribute_result =
-                            Self::match_svg_attribute(&reader, &file_path, &mut check_errors);
-                        let match_todo_result = Self::match_todo(&reader, &mut check_errors);
-                        let match_image_alt = Self::match_image_alt(&reader, &mut check_errors);
-                        let match_a_rel = Self::match_a_rel(&reader, &mut check_errors);
-
-                        let check_file_lines = Self::check_file_lines(
-                            &file_path,
-                            &mut check_errors,
-                            &diff_add_files,
-                            &reader,
-                        );
-
-                        let match_console_log_result = Self::match_console_log(&reader, &mut check_errors);
-
-                        let ts_table = TsTable {
-                            lint_check: lint_ts_result,
-                            svg_check: match_svg_attribute_result,
-                            todo_check: match_todo_result,
-                            color_check: match_color_result,
-                            image_alt_check: match_image_alt,
-                            a_rel_check: match_a_rel,
-                            file_line_check: check_file_lines,
-                            console_log_check: match_console_log_result,
-                        };
-
-                        DrawTable::draw_ts_table(&file_path, ts_table);
-                    }
-                    "go" => {
-                        let lint_go_result = Self::lint_go(&file_path);
-                        let match_todo_result = Self::match_todo(&reader, &mut check_errors);
-                        let check_file_lines = Self::check_file_lines(
-                            &file_path,
-                            &mut check_errors,
-                            &diff_add_files,
-                            &reader,
-                        );
-
-                        let go_table = GoTable {
-                            lint_check: lint_go_result,
-                            todo_check: match_todo_result,
-                            file_line_check: check_file_lines,
-                        };
+    pub fn run(
+        config: &Config,
+        registry: &Registry,
+        project: &ProjectConfig,
+    ) -> Result<Report, LintError> {
+        let added_files: HashSet<PathBuf> = Git::added_files()?
+            .iter()
+            .map(|file_path| FileDiscovery::canonical(file_path))
+            .collect();
+        let file_paths = Self::file_paths(config, project)?;
+        let mut files = Vec::new();
+        let mut complete = Vec::new();
+        let mut tasks = Vec::new();
+
+        for file_path in &file_paths {
+            let extension = match get_extension(file_path) {
+                Some(ext) => ext,
+                None => continue,
+            };
+            // unused suppressions are reported once the other rules ran
+            let (rules, skipped): (Vec<_>, Vec<_>) = registry
+                .rules_for(&extension)
+                .into_iter()
+                .filter(|rule| rule.id() != UNUSED_SUPPRESSION)
+                .partition(|rule| {
+                    config.rules.is_empty() || config.rules.iter().any(|id| id == rule.id())
+                });
+            if rules.is_empty() {
+                continue;
+            }
+            // suppressions without a rule can only be judged when every enabled rule ran
+            complete.push(
+                skipped
+                    .iter()
+                    .all(|rule| !project.resolve(rule.id(), file_path).enabled),
+            );
 
-                        DrawTable::draw_go_table(&file_path, go_table);
-                    }
-                    _ => {
-                        std::process::exit(0);
-                    }
-                },
-                Ok(None) => {}
-                Err(err) => {
-                    eprintln!("Error: {}", err);
-                    std::process::exit(1);
+            for rule in rules {
+                let resolved = project.resolve(rule.id(), file_path);
+                if resolved.enabled {
+                    tasks.push(Task {
+                        file: files.len(),
+                        rule,
+                        resolved,
+                    });
                 }
             }
-        }
 
-        if check_errors > 0 {
-            println!("All errors total {}", check_errors.to_string().red().bold());
-            std::process::exit(1);
-        } else {
-            println!(
-                "All errors total {}",
-                check_errors.to_string().green().bold()
-            );
+            let contents = if config.staged {
+                Git::read_index(file_path)?
+            } else {
+                fs::read_to_string(file_path).map_err(|e| LintError::io(file_path, e))?
+            };
+            files.push(SourceFile {
+                contents,
+                is_added: added_files.contains(&FileDiscovery::canonical(file_path)),
+                staged: config.staged,
+                path: file_path.clone(),
+                extension,
+            });
         }
 
-        Ok(())
-    }
+        let jobs = config.jobs.unwrap_or_else(|| {
+            thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1)
+        });
+        let mut cache = if config.cache { Cache::open()? } else { None };
+        let changed_lines = if config.changed_lines {
+            Some(ChangedLines::load(
+                config.diff_base.as_deref(),
+                config.staged,
+            )?)
+        } else {
+            None
+        };
+        let results = Self::parallel_map(jobs, &tasks, |task| {
+            Self::check(task, &files[task.file], cache.as_ref())
+        });
+
+        let mut report = Report {
+            files: files
+                .iter()
+                .map(|file| FileReport {
+                    path: file.path.clone(),
+                    checks: Vec::new(),
+                })
+                .collect(),
+            ..Report::default()
+        };
+        let mut suppressions: Vec<Suppressions> = files
+            .iter()
+            .map(|file| Suppressions::parse(&file.contents))
+            .collect();
+        for (task, result) in tasks.iter().zip(results) {
+            let (fresh_key, mut diagnostics) = result?;
+            if let (Some(cache), Some(key)) = (cache.as_mut(), fresh_key) {
+                cache.insert(
+                    &files[task.file].path,
+                    task.rule.id(),
+                    key,
+                    diagnostics.clone(),
+                );
+            }
 
-    fn match_todo<'a>(contents: &'a str, check_errors: &mut u16) -> LintResult {
-        let mut result = Vec::new();
-        for (line_number, line) in contents.lines().enumerate() {
-            let line = line.trim_start();
-            if line.starts_with("//") && line.contains(TODO_SEARCH) {
-                if !line.contains(TODO_IGNORE_SEARCH) {
-                    let r = format!("line {} has TODO {}", line_number, line);
-                    result.push(r);
+            Self::cap_severity(&mut diagnostics, task.rule, &task.resolved);
+            Self::fingerprint(
+                &mut diagnostics,
+                &files[task.file].contents,
+                task.rule.line_scoped(),
+            );
+            let found = Self::fingerprints(&diagnostics);
+            suppressions[task.file].apply(&mut diagnostics);
+            if let Some(changed_lines) = &changed_lines {
+                let line_scoped = task.resolved.line_scoped.unwrap_or(task.rule.line_scoped());
+                if line_scoped {
+                    changed_lines.retain(&files[task.file].path, &mut diagnostics);
                 }
             }
-        }
 
-        *check_errors += result.len() as u16;
-
-        LintResult {
-            errors: result.len(),
-            result,
+            report.files[task.file].checks.push(RuleCheck {
+                rule_id: task.rule.id().to_string(),
+                label: task.rule.label(),
+                diagnostics,
+                found,
+            });
         }
-    }
 
-    fn match_console_log<'a>(contents: &'a str, check_errors: &mut u16) -> LintResult {
-        let mut match_necessary = 0;
-        let mut match_console = 0;
-        let mut result = Vec::new();
-        for line in contents.lines() {
-            let line = line.trim_start();
-            if line.starts_with("//") && line.contains(NECESSARY_CONSOLE_LOGGING) {
-                match_necessary += 1;
+        // files with suppression comments get a row listing the ones silencing nothing
+        let unused_rule = registry.get(UNUSED_SUPPRESSION);
+        for (((file, suppressions), file_report), &complete) in files
+            .iter()
+            .zip(&suppressions)
+            .zip(report.files.iter_mut())
+            .zip(&complete)
+        {
+            let Some(rule) = unused_rule else {
+                break;
+            };
+            if suppressions.is_empty() {
+                continue;
+            }
+            let resolved = project.resolve(rule.id(), &file.path);
+            if !resolved.enabled {
+                continue;
             }
-            if line.contains(CONSOLE_LOG) {
-                match_console += 1;
+            let checked: Vec<&str> = file_report
+                .checks
+                .iter()
+                .map(|check| check.rule_id.as_str())
+                .collect();
+            let mut diagnostics = suppressions.unused(&file.path, &checked, complete, |id| {
+                registry.get(id).is_some()
+            });
+            Self::cap_severity(&mut diagnostics, rule, &resolved);
+            Self::fingerprint(&mut diagnostics, &file.contents, true);
+            let found = Self::fingerprints(&diagnostics);
+            if let Some(changed_lines) = &changed_lines {
+                changed_lines.retain(&file.path, &mut diagnostics);
             }
+            file_report.checks.push(RuleCheck {
+                rule_id: rule.id().to_string(),
+                label: rule.label(),
+                diagnostics,
+                found,
+            });
         }
 
-        let difference = match_console - match_necessary;
-        if difference > 0 {
-            *check_errors += difference;
-            let r = format!("file has {} console.log", difference);
-            result.push(r);
+        if let Some(cache) = &cache {
+            cache.save()?;
         }
 
-        LintResult {
-            errors: difference as usize,
-            result,
-        }
+        Ok(report)
     }
 
-    fn match_a_rel<'a>(contents: &'a str, check_errors: &mut u16) -> LintResult {
-        let mut result = Vec::new();
-        let re = Regex::new(r#"href=[^>]*>"#).unwrap();
-        for cap in re.find_iter(&contents) {
-            let line = &contents[cap.start()..cap.end()];
-            if !line.contains("rel=") {
-                let r = format!("a tag need set <rel> value: {}", line);
-                result.push(r);
+    // runs the rule unless the cache holds its result, the key is returned when
+    // the result is fresh and should be stored
+    fn check(
+        task: &Task,
+        file: &SourceFile,
+        cache: Option<&Cache>,
+    ) -> Result<(Option<String>, Vec<Diagnostic>), LintError> {
+        let options = &task.resolved.options;
+        let key = match cache.filter(|_| task.rule.cacheable()) {
+            Some(cache) => {
+                let key = Cache::key(task.rule, options, file)?;
+                if let Some(diagnostics) = cache.get(&file.path, task.rule.id(), &key) {
+                    return Ok((None, diagnostics));
+                }
+                Some(key)
             }
-        }
+            None => None,
+        };
 
-        *check_errors += result.len() as u16;
-
-        LintResult {
-            errors: result.len(),
-            result,
-        }
+        Ok((key, task.rule.check(file, options)?))
     }
 
-    fn match_image_alt<'a>(contents: &'a str, check_errors: &mut u16) -> LintResult {
-        let mut result = Vec::new();
-        let re = Regex::new(r#"<Image[^>]*>"#).unwrap();
-        for cap in re.find_iter(&contents) {
-            let line = &contents[cap.start()..cap.end()];
-            if !line.contains("alt=") {
-                let r = format!("img tag need set <alt> value: {}", line);
-                result.push(r);
+    // hash of the rule, the message and the trimmed source line, numbered among equal
+    // ones so it survives edits elsewhere in the file; rules judging the whole file only
+    // hash the rule, their message and position change with the file length. The path
+    // is left out, the baseline and the formatters pair it with the fingerprint
+    fn fingerprint(diagnostics: &mut [Diagnostic], contents: &str, line_scoped: bool) {
+        let lines: Vec<&str> = contents.lines().collect();
+        let mut seen: HashMap<Vec<u8>, usize> = HashMap::new();
+        for diagnostic in diagnostics.iter_mut() {
+            let mut hasher = Sha256::new();
+            hasher.update(&diagnostic.rule_id);
+            if line_scoped {
+                let line = lines
+                    .get(diagnostic.start.line.saturating_sub(1))
+                    .map_or("", |line| line.trim());
+                hasher.update([0]);
+                hasher.update(&diagnostic.message);
+                hasher.update([0]);
+                hasher.update(line);
             }
-        }
-
-        *check_errors += result.len() as u16;
-
-        LintResult {
-            errors: result.len(),
-            result,
+            let base = hasher.finalize().to_vec();
+
+            let occurrence = seen.entry(base.clone()).or_insert(0);
+            *occurrence += 1;
+            let mut hasher = Sha256::new();
+            hasher.update(&base);
+            hasher.update(occurrence.to_le_bytes());
+            diagnostic.fingerprint = to_hex(&hasher.finalize()[..16]);
         }
     }
 
-    fn match_tsx_color(
-        contents: &str,
-        check_errors: &mut u16,
-    ) -> Result<LintResult, Box<dyn Error>> {
-        let color_contents = fs::read_to_string(&RE_TSX_THEME_FILE)?;
-        let mut result = Vec::new();
-        let re_s = Regex::new(r"xmlns").unwrap();
-
-        if re_s.is_match(contents) {
-            return Ok(LintResult {
-                errors: result.len(),
-                result,
-            });
-        }
-
-        let re_a = Regex::new(&RE_MATCH_COLOR).unwrap();
-        let mut colors: Vec<String> = Vec::new();
-        for cap in re_a.captures_iter(&contents) {
-            let color_with_equal = &cap[0];
-            colors.push(color_with_equal.to_string());
-        }
-
-        let re_b = Regex::new(&RE_MATCH_COLOR).unwrap();
-        for line in color_contents.lines() {
-            for cap in re_b.captures_iter(line) {
-                let color = &cap[0];
-                if colors.contains(&color.to_string()) {
-                    let r = format!(
-                        "Color {} need replace theme.ts definition",
-                        color.to_string()
-                    );
-                    result.push(r);
-                }
-            }
+    // the rule severity caps the level of every diagnostic, so eslint warnings
+    // stay warnings while `severity = "warning"` downgrades its errors
+    fn cap_severity(diagnostics: &mut [Diagnostic], rule: &dyn Rule, resolved: &ResolvedRule) {
+        let severity = resolved.severity.unwrap_or(rule.default_severity());
+        for diagnostic in diagnostics.iter_mut() {
+            diagnostic.severity = diagnostic.severity.max(severity);
         }
-        let mut unique_result: HashSet<_> = result.drain(..).collect();
-        result.extend(unique_result.drain());
-
-        *check_errors += result.len() as u16;
-
-        Ok(LintResult {
-            errors: result.len(),
-            result,
-        })
     }
 
-    fn lint_ts(file_path: &str, check_errors: &mut u16) -> LintResult {
-        let output = Command::new("eslint")
-            .arg(file_path)
-            .output()
-            .expect("Failed to run ESLint");
-        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    fn fingerprints(diagnostics: &[Diagnostic]) -> Vec<String> {
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.fingerprint.clone())
+            .collect()
+    }
 
-        let mut result = Vec::new();
-        let re = Regex::new(&RE_LINT_TS).unwrap();
+    // maps `items` on `jobs` threads, results keep the order of `items`
+    fn parallel_map<T, R, F>(jobs: usize, items: &[T], f: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync,
+    {
+        let next = AtomicUsize::new(0);
+        let workers = jobs.clamp(1, items.len().max(1));
+        let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut done = Vec::new();
+                        loop {
+                            let i = next.fetch_add(1, Ordering::Relaxed);
+                            if i >= items.len() {
+                                break;
+                            }
+                            done.push((i, f(&items[i])));
+                        }
+                        done
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+                .collect()
+        });
+
+        results.sort_by_key(|(i, _)| *i);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
 
-        for line in stdout.lines() {
-            if re.is_match(line) {
-                result.push(line.trim().to_string());
-            }
+    // in staged mode the inputs only narrow down the staged files
+    fn file_paths(config: &Config, project: &ProjectConfig) -> Result<Vec<String>, LintError> {
+        if !config.staged {
+            return FileDiscovery::expand(&config.file_paths, &project.root);
         }
 
-        *check_errors += result.len() as u16;
-
-        LintResult {
-            errors: result.len(),
-            result,
+        let staged_files = Git::staged_files()?;
+        // an explicit file that is not staged would otherwise be skipped silently
+        let all_staged: HashSet<PathBuf> = staged_files
+            .iter()
+            .map(|file_path| FileDiscovery::canonical(file_path))
+            .collect();
+        let staged = FileDiscovery::retain_not_ignored(staged_files, &project.root);
+        if config.file_paths.is_empty() {
+            return Ok(staged);
         }
-    }
-
-    fn git_add_files() -> Vec<String> {
-        let mut result = Vec::new();
-        let output = Command::new("git")
-            .arg("status")
-            .arg("--porcelain")
-            .output()
-            .expect("Failed to run golangci-lint");
-
-        let stdout: String = String::from_utf8_lossy(&output.stdout).into_owned();
-
-        for line in stdout.lines() {
-            if line.starts_with("A") {
-                result.push(line.to_string());
-            }
+        if let Some(input) = config.file_paths.iter().find(|input| {
+            Path::new(input).is_file() && !all_staged.contains(&FileDiscovery::canonical(input))
+        }) {
+            return Err(LintError::Git(format!("{} is not staged", input)));
         }
 
-        result
+        let selected: HashSet<PathBuf> = FileDiscovery::expand(&config.file_paths, &project.root)?
+            .iter()
+            .map(|file_path| FileDiscovery::canonical(file_path))
+            .collect();
+        Ok(staged
+            .into_iter()
+            .filter(|file_path| selected.contains(&FileDiscovery::canonical(file_path)))
+            .collect())
     }
+}
 
-    fn check_file_lines(
-        file_path: &str,
-        check_errors: &mut u16,
-        diff_add_files: &Vec<String>,
-        contents: &str,
-    ) -> LintResult {
-        let mut result = Vec::new();
-        let len = contents.lines().count();
-
-        let f = format!("A  {}", &file_path.to_string());
-        if diff_add_files.contains(&f) && len > FILE_LINE as usize {
-            let r = format!("File cannot be larger than {} lines", FILE_LINE);
-            result.push(r);
-        }
-
-        *check_errors += result.len() as u16;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::Position;
 
-        LintResult {
-            errors: result.len(),
-            result,
-        }
+    fn todo(line: usize) -> Diagnostic {
+        let position = Position::new(line, 1);
+        Diagnostic::new("todo", "a.ts", "has unresolved marker".to_string()).at(position, position)
     }
 
-    fn lint_go(file_path: &str) -> LintResult {
-        let output = Command::new("golangci-lint")
-            .args(&["run", &file_path])
-            .output()
-            .expect("Failed to run golangci-lint");
-
-        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
-
-        let re = Regex::new(&RE_LINT_GO).unwrap();
-        let result: Vec<String> = re
-            .captures_iter(&stdout)
-            .map(|cap| cap.get(1).unwrap().as_str().to_string())
-            .collect();
-
-        LintResult {
-            errors: result.len(),
-            result,
-        }
+    fn fingerprints(lines: &[usize], contents: &str, line_scoped: bool) -> Vec<String> {
+        let mut diagnostics: Vec<Diagnostic> = lines.iter().map(|&line| todo(line)).collect();
+        CheckFile::fingerprint(&mut diagnostics, contents, line_scoped);
+        CheckFile::fingerprints(&diagnostics)
     }
 
-    fn match_svg_attribute(contents: &str, file_path: &str, check_errors: &mut u16) -> LintResult {
-        let mut result = Vec::new();
-        if file_path.ends_with(".tsx") {
-            for attribute_name in &SVG_ATTRIBUTE_NAMES {
-                let re = Regex::new(&format!(r#"{}"#, attribute_name)).unwrap();
-                let r: String = format!(
-                    "need replace {} to {}",
-                    attribute_name.to_string(),
-                    convert_to_camel_case(attribute_name)
-                );
-                if re.is_match(&contents) {
-                    result.push(r);
-                }
-            }
-        }
+    #[test]
+    fn fingerprints_survive_moved_lines() {
+        let before = fingerprints(&[1, 2], "// TODO a\n  // TODO b\n", true);
+        let after = fingerprints(&[3, 5], "\n\n// TODO a\n\n// TODO b\n", true);
+        assert_eq!(before, after);
+        assert_eq!(before[0].len(), 32);
+        assert_ne!(
+            before,
+            fingerprints(&[1, 2], "// TODO c\n// TODO b\n", true)
+        );
+    }
 
-        *check_errors += result.len() as u16;
+    #[test]
+    fn equal_problems_are_numbered() {
+        let prints = fingerprints(&[1, 2, 3], "// TODO a\n// TODO a\n// TODO b\n", true);
+        assert_ne!(prints[0], prints[1]);
+        let moved = fingerprints(&[2, 3, 4], "x\n// TODO a\n// TODO a\n// TODO b\n", true);
+        assert_eq!(moved, prints);
+        // with one of the equal problems fixed, the other keeps the first number
+        let fixed = fingerprints(&[1, 2], "// TODO a\n// TODO b\n", true);
+        assert_eq!(fixed, [prints[0].clone(), prints[2].clone()]);
+    }
 
-        LintResult {
-            errors: result.len(),
-            result,
-        }
+    #[test]
+    fn whole_file_fingerprints_only_hash_the_rule() {
+        let mut diagnostics = vec![todo(1)];
+        CheckFile::fingerprint(&mut diagnostics, "a\n", false);
+        let mut longer = vec![todo(9)];
+        longer[0].message = "other".to_string();
+        CheckFile::fingerprint(&mut longer, "b\n", false);
+        assert_eq!(diagnostics[0].fingerprint, longer[0].fingerprint);
     }
 }
@@ -0,0 +1,184 @@
+use crate::{Config, Format};
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(
+    name = "lint_master",
+    version,
+    about = "A tool to detect git commit code"
+)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    // `lint_master <FILES>` is kept as a shorthand for `lint_master check <FILES>`
+    #[command(flatten)]
+    pub check: CheckArgs,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Check files and print the report
+    Check(CheckArgs),
+    /// Fix the problems that can be fixed automatically, same as `check --fix`
+    Fix(CheckArgs),
+    /// Record the problems found so far in lint-master-baseline.json, check leaves them out
+    Baseline(BaselineArgs),
+    /// List the available rules
+    Rules {
+        /// Path to lint-master.toml, discovered from the current directory by default
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+    },
+    /// Show the documentation of a rule
+    Explain {
+        /// Rule id, as listed by `lint_master rules`
+        rule: String,
+    },
+    /// Install a git pre-commit hook checking the staged files
+    ///
+    /// An existing pre-commit hook is kept and runs first. Set LINT_MASTER_SKIP=1 to skip the hook.
+    InstallHook {
+        /// Remove the hook and restore the one it replaced
+        #[arg(long)]
+        uninstall: bool,
+
+        /// Fix the staged files before checking them, fixed files are staged again
+        #[arg(long, conflicts_with = "uninstall")]
+        fix: bool,
+    },
+    /// Write a default lint-master.toml into the current directory
+    Init {
+        /// Overwrite an existing lint-master.toml
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Args, Clone, Default)]
+pub struct CheckArgs {
+    /// Files, directories or globs to check
+    #[arg(required_unless_present = "staged")]
+    pub paths: Vec<String>,
+
+    /// Path to lint-master.toml, discovered from the current directory by default
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+
+    /// Output format, github when GITHUB_ACTIONS=true and table otherwise by default
+    #[arg(short, long, value_enum)]
+    pub format: Option<Format>,
+
+    /// Write the report to FILE instead of stdout, not available for the table
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Only run the given rule, may be repeated
+    #[arg(short, long = "rule", value_name = "RULE")]
+    pub rules: Vec<String>,
+
+    /// Fail when there are more than N warnings, errors always fail
+    #[arg(long, value_name = "N")]
+    pub max_warnings: Option<usize>,
+
+    /// Number of files and rules checked in parallel, defaults to the number of CPUs
+    #[arg(short, long, value_name = "N")]
+    pub jobs: Option<usize>,
+
+    /// Do not reuse or store results in .git/lint-master
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Check the contents staged in the git index, every staged file when no paths are given
+    #[arg(long)]
+    pub staged: bool,
+
+    /// Only report problems on lines changed since HEAD, or staged lines with --staged
+    #[arg(long)]
+    pub changed_lines: bool,
+
+    /// Only report problems on lines changed since REF, implies --changed-lines
+    #[arg(long, value_name = "REF")]
+    pub diff_base: Option<String>,
+
+    /// Baseline of accepted problems, lint-master-baseline.json next to lint-master.toml by default
+    #[arg(long, value_name = "FILE")]
+    pub baseline_file: Option<PathBuf>,
+
+    /// Report the problems recorded in the baseline too
+    #[arg(long)]
+    pub no_baseline: bool,
+
+    /// Fix the problems that can be fixed automatically and report the remaining ones
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Print the fixes as a unified diff instead of writing them, implies --fix
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+impl From<CheckArgs> for Config {
+    fn from(args: CheckArgs) -> Config {
+        Config {
+            file_paths: args.paths,
+            config_file: args.config,
+            format: args.format.unwrap_or_else(Format::detect),
+            output: args.output,
+            rules: args.rules,
+            max_warnings: args.max_warnings,
+            jobs: args.jobs,
+            cache: !args.no_cache,
+            staged: args.staged,
+            changed_lines: args.changed_lines || args.diff_base.is_some(),
+            diff_base: args.diff_base,
+            baseline: !args.no_baseline,
+            baseline_file: args.baseline_file,
+            fix: args.fix || args.dry_run,
+            dry_run: args.dry_run,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct BaselineArgs {
+    /// Files, directories or globs to check
+    #[arg(required = true)]
+    pub paths: Vec<String>,
+
+    /// Path to lint-master.toml, discovered from the current directory by default
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+
+    /// Only run the given rule, may be repeated
+    #[arg(short, long = "rule", value_name = "RULE")]
+    pub rules: Vec<String>,
+
+    /// Number of files and rules checked in parallel, defaults to the number of CPUs
+    #[arg(short, long, value_name = "N")]
+    pub jobs: Option<usize>,
+
+    /// Do not reuse or store results in .git/lint-master
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// File to write, lint-master-baseline.json next to lint-master.toml by default
+    #[arg(long, value_name = "FILE")]
+    pub baseline_file: Option<PathBuf>,
+}
+
+impl From<BaselineArgs> for Config {
+    fn from(args: BaselineArgs) -> Config {
+        Config {
+            file_paths: args.paths,
+            config_file: args.config,
+            rules: args.rules,
+            jobs: args.jobs,
+            cache: !args.no_cache,
+            baseline_file: args.baseline_file,
+            ..Config::default()
+        }
+    }
+}
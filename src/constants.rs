@@ -1,10 +1,26 @@
-pub const TODO_SEARCH: &'static str = "TODO";
-pub const TODO_IGNORE_SEARCH: &'static str = "IGNORE";
-pub const NECESSARY_CONSOLE_LOGGING: &'static str = "Necessary console logging";
-pub const CONSOLE_LOG: &'static str = "console.log(";
-pub const FILE_LINE: u8 = 150;
-pub const CONGRATULATE: &'static str = "✨ Congratulate all passed 🎉🎉🎉";
-pub const WELCOME: &'static str = r#"
+use crate::diagnostic::{Diagnostic, Severity};
+
+pub const TODO_SEARCH: &str = "TODO";
+pub const TODO_IGNORE_SEARCH: &str = "IGNORE";
+pub const NECESSARY_CONSOLE_LOGGING: &str = "Necessary console logging";
+pub const CONSOLE_LOG: &str = "console.log(";
+pub const REL_NOOPENER: &str = r#"rel="noopener noreferrer""#;
+pub const FILE_LINE: usize = 150;
+pub const CONFIG_FILE_NAME: &str = "lint-master.toml";
+pub const IGNORE_FILE_NAME: &str = ".lintmasterignore";
+pub const CACHE_DIR: &str = "lint-master";
+pub const HOOK_MARKER: &str = "# lint_master pre-commit hook";
+pub const HOOK_CHAINED_SUFFIX: &str = ".lint-master-chained";
+pub const HOOK_SKIP_ENV: &str = "LINT_MASTER_SKIP";
+pub const CACHE_FILE_NAME: &str = "cache.json";
+pub const UNUSED_SUPPRESSION: &str = "unused-suppression";
+pub const BASELINE_FILE_NAME: &str = "lint-master-baseline.json";
+pub const BASELINE_VERSION: u32 = 1;
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+pub const GITHUB_ACTIONS_ENV: &str = "GITHUB_ACTIONS";
+pub const GITHUB_STEP_SUMMARY_ENV: &str = "GITHUB_STEP_SUMMARY";
+pub const CONGRATULATE: &str = "✨ Congratulate all passed 🎉🎉🎉";
+pub const WELCOME: &str = r#"
     __     ____ _   __ ______   __  ___ ___    _____ ______ ______ ____ 
    / /    /  _// | / //_  __/  /  |/  //   |  / ___//_  __// ____// __ \
   / /     / / /  |/ /  / /    / /|_/ // /| |  \__ \  / /  / __/  / /_/ /
@@ -15,15 +31,30 @@ pub const WELCOME: &'static str = r#"
 
 pub struct LintResult {
     pub errors: usize,
+    pub warnings: usize,
     pub result: Vec<String>,
 }
 
-pub const RE_LINT_TS: &'static str = r"^\s*\d+:\d+\s+error.*";
-pub const RE_LINT_GO: &'static str = r".*?:(\d+:\d+:\s.*?(?:\n\s+.*?)+)";
-pub const RE_MATCH_COLOR: &'static str = r"#[0-9a-fA-F]{6}";
-pub const RE_TSX_THEME_FILE: &'static str = "apps/identity-hub/config/theme.ts";
+impl LintResult {
+    pub fn from_diagnostics(diagnostics: &[Diagnostic]) -> LintResult {
+        let count = |severity| diagnostics.iter().filter(|d| d.severity == severity).count();
+        LintResult {
+            errors: count(Severity::Error),
+            warnings: count(Severity::Warning),
+            result: diagnostics.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+}
+
+pub const RE_LINT_TS: &str = r"^\s*(\d+):(\d+)\s+(error|warning)\s+(.*?)(?:\s{2,}(\S+))?\s*$";
+pub const RE_LINT_GO: &str = r"(?m)^.+?:(\d+):(\d+):\s(.*)$";
+pub const RE_SUPPRESSION: &str =
+    r"(?m)(?://|#|/\*)[ \t]*lint-master-(disable-next-line|disable-line|disable|enable)(?:[ \t]+(.*?))?[ \t]*(?:\*/.*)?$";
+pub const RE_DIFF_HUNK: &str = r"^@@ -\d+(?:,\d+)? \+(\d+)(?:,(\d+))? @@";
+pub const RE_MATCH_COLOR: &str = r"#[0-9a-fA-F]{6}";
+pub const RE_TSX_THEME_FILE: &str = "apps/identity-hub/config/theme.ts";
 
-pub const SVG_ATTRIBUTE_NAMES: [&str; 36] = [
+pub const SVG_ATTRIBUTE_NAMES: [&str; 35] = [
     "fill-rule",
     "clip-rule",
     "fill-opacity",
@@ -47,7 +78,6 @@ pub const SVG_ATTRIBUTE_NAMES: [&str; 36] = [
     "image-rendering",
     "color-interpolation",
     "color-interpolation-filters",
-    "shape-rendering",
     "color-rendering",
     "flood-color",
     "flood-opacity",
@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// ordered from most to least severe
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// 1-based line and column
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    pub fn new(line: usize, column: usize) -> Position {
+        Position { line, column }
+    }
+
+    pub fn from_offset(contents: &str, offset: usize) -> Position {
+        let before = &contents[..offset];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        Position {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+// replaces the bytes `start..end` of the checked contents
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Edit {
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
+}
+
+impl Edit {
+    pub fn new(start: usize, end: usize, replacement: String) -> Edit {
+        Edit {
+            start,
+            end,
+            replacement,
+        }
+    }
+}
+
+// edits resolving a diagnostic, applied all together or not at all
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fix {
+    pub description: String,
+    pub edits: Vec<Edit>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub path: String,
+    pub start: Position,
+    pub end: Position,
+    pub rule_id: String,
+    // rule of the wrapped linter, e.g. `no-unused-vars` for eslint
+    #[serde(default)]
+    pub code: Option<String>,
+    pub severity: Severity,
+    pub message: String,
+    pub suggestion: Option<String>,
+    // identifies the problem independently of its line number, set by the runner
+    #[serde(default)]
+    pub fingerprint: String,
+    #[serde(default)]
+    pub fix: Option<Fix>,
+}
+
+impl Diagnostic {
+    pub fn new(rule_id: &str, path: &str, message: String) -> Diagnostic {
+        Diagnostic {
+            path: path.to_string(),
+            start: Position::new(1, 1),
+            end: Position::new(1, 1),
+            rule_id: rule_id.to_string(),
+            code: None,
+            severity: Severity::Error,
+            message,
+            suggestion: None,
+            fingerprint: String::new(),
+            fix: None,
+        }
+    }
+
+    pub fn at(mut self, start: Position, end: Position) -> Diagnostic {
+        self.start = start;
+        self.end = end;
+        self
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Diagnostic {
+        self.severity = severity;
+        self
+    }
+
+    pub fn with_code(mut self, code: &str) -> Diagnostic {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    pub fn with_suggestion(mut self, suggestion: String) -> Diagnostic {
+        self.suggestion = Some(suggestion);
+        self
+    }
+
+    pub fn with_fix(mut self, description: String, edits: Vec<Edit>) -> Diagnostic {
+        self.fix = Some(Fix { description, edits });
+        self
+    }
+
+    // `eslint/no-unused-vars` for the rules of wrapped linters, the rule id otherwise
+    pub fn qualified_rule_id(&self) -> String {
+        match &self.code {
+            Some(code) => format!("{}/{}", self.rule_id, code),
+            None => self.rule_id.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{} {} {}",
+            self.start.line, self.start.column, self.severity, self.message
+        )
+    }
+}
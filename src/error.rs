@@ -0,0 +1,88 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum LintError {
+    Config(String),
+    Git(String),
+    // `operation` is what failed, e.g. "read" or "write"
+    Io {
+        operation: &'static str,
+        path: String,
+        source: io::Error,
+    },
+    Command {
+        program: String,
+        source: io::Error,
+    },
+}
+
+impl LintError {
+    pub fn io(path: &str, source: io::Error) -> LintError {
+        LintError::Io {
+            operation: "read",
+            path: path.to_string(),
+            source,
+        }
+    }
+
+    pub fn write(path: &str, source: io::Error) -> LintError {
+        LintError::Io {
+            operation: "write",
+            path: path.to_string(),
+            source,
+        }
+    }
+
+    pub fn command(program: &str, source: io::Error) -> LintError {
+        LintError::Command {
+            program: program.to_string(),
+            source,
+        }
+    }
+}
+
+impl fmt::Display for LintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LintError::Config(message) | LintError::Git(message) => write!(f, "{}", message),
+            LintError::Io {
+                operation,
+                path,
+                source,
+            } => write!(f, "failed to {} {}: {}", operation, path, source),
+            LintError::Command { program, source } => {
+                write!(f, "failed to run {}: {}", program, source)
+            }
+        }
+    }
+}
+
+impl Error for LintError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LintError::Config(_) | LintError::Git(_) => None,
+            LintError::Io { source, .. } | LintError::Command { source, .. } => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn io_errors_name_the_operation() {
+        let denied = || io::Error::new(io::ErrorKind::PermissionDenied, "denied");
+
+        assert_eq!(
+            LintError::io("a.ts", denied()).to_string(),
+            "failed to read a.ts: denied"
+        );
+        assert_eq!(
+            LintError::write("lint-master-baseline.json", denied()).to_string(),
+            "failed to write lint-master-baseline.json: denied"
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use crate::constants::IGNORE_FILE_NAME;
+use crate::error::LintError;
+use globset::GlobBuilder;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::WalkBuilder;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub struct FileDiscovery {}
+
+impl FileDiscovery {
+    // expands directories and globs into files, walks honor .gitignore and
+    // .lintmasterignore while explicit files only honor the root .lintmasterignore
+    pub fn expand(inputs: &[String], root: &Path) -> Result<Vec<String>, LintError> {
+        let ignore = Self::root_ignore(root);
+        let mut seen = HashSet::new();
+        let mut result = Vec::new();
+
+        for input in inputs {
+            let files = if Self::is_glob(input) {
+                Self::walk_glob(input)?
+            } else if Path::new(input).is_dir() {
+                Self::walk(Path::new(input), |_| true)?
+            } else if Path::new(input).is_file() {
+                vec![input.clone()]
+            } else {
+                return Err(LintError::io(
+                    input,
+                    io::Error::new(io::ErrorKind::NotFound, "no such file or directory"),
+                ));
+            };
+
+            // the walk never filters its own start, e.g. `gen` with `gen/` ignored
+            for file in files {
+                if !Self::is_ignored(&ignore, &file) && seen.insert(file.clone()) {
+                    result.push(file);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    // drops the files matched by the root .lintmasterignore
+    pub fn retain_not_ignored(file_paths: Vec<String>, root: &Path) -> Vec<String> {
+        let ignore = Self::root_ignore(root);
+        file_paths
+            .into_iter()
+            .filter(|file_path| !Self::is_ignored(&ignore, file_path))
+            .collect()
+    }
+
+    // absolute path to compare paths given relative to different directories, e.g.
+    // `b.ts` in `src/` and the staged `../src/b.ts`
+    pub fn canonical(file_path: &str) -> PathBuf {
+        fs::canonicalize(file_path).unwrap_or_else(|_| PathBuf::from(file_path))
+    }
+
+    fn is_glob(input: &str) -> bool {
+        input.contains(['*', '?', '[', '{'])
+    }
+
+    fn walk_glob(pattern: &str) -> Result<Vec<String>, LintError> {
+        let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+        // `*` stays within a directory, only `**` recurses
+        let matcher = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .map_err(|e| LintError::Config(format!("invalid glob `{}`: {}", pattern, e)))?
+            .compile_matcher();
+
+        // walk from the longest directory prefix without glob characters
+        let base: PathBuf = Path::new(pattern)
+            .components()
+            .take_while(|component| !Self::is_glob(&component.as_os_str().to_string_lossy()))
+            .collect();
+        let base = if base.as_os_str().is_empty() || !base.is_dir() {
+            PathBuf::from(".")
+        } else {
+            base
+        };
+
+        Self::walk(&base, |path| matcher.is_match(path))
+    }
+
+    fn walk(dir: &Path, keep: impl Fn(&Path) -> bool) -> Result<Vec<String>, LintError> {
+        let mut result = Vec::new();
+        let walker = WalkBuilder::new(dir)
+            .add_custom_ignore_filename(IGNORE_FILE_NAME)
+            .sort_by_file_name(|a, b| a.cmp(b))
+            .build();
+
+        for entry in walker {
+            let entry = entry
+                .map_err(|e| LintError::io(&dir.display().to_string(), io::Error::other(e)))?;
+            if !entry
+                .file_type()
+                .is_some_and(|file_type| file_type.is_file())
+            {
+                continue;
+            }
+
+            let path = entry.path().strip_prefix(".").unwrap_or(entry.path());
+            if keep(path) {
+                result.push(path.to_string_lossy().into_owned());
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn root_ignore(root: &Path) -> Gitignore {
+        let mut builder = GitignoreBuilder::new(root);
+        builder.add(root.join(IGNORE_FILE_NAME));
+        builder.build().unwrap_or_else(|_| Gitignore::empty())
+    }
+
+    fn is_ignored(ignore: &Gitignore, file_path: &str) -> bool {
+        match fs::canonicalize(file_path) {
+            Ok(path) => ignore.matched_path_or_any_parents(&path, false).is_ignore(),
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a temporary tree with the given files, removed by the caller
+    fn tree(name: &str, files: &[&str]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!(
+            "lint-master-discovery-{}-{}",
+            name,
+            std::process::id()
+        ));
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+        fs::canonicalize(root).unwrap()
+    }
+
+    fn expand(root: &Path, inputs: &[&str]) -> Vec<String> {
+        let inputs: Vec<String> = inputs
+            .iter()
+            .map(|input| root.join(input).to_string_lossy().into_owned())
+            .collect();
+        let files = FileDiscovery::expand(&inputs, root).unwrap();
+        files
+            .iter()
+            .map(|file| {
+                file.strip_prefix(&format!("{}/", root.display()))
+                    .unwrap()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn globs_walk_from_their_base() {
+        let root = tree(
+            "glob",
+            &["src/a.ts", "src/b.go", "src/nested/c.ts", "other/d.ts"],
+        );
+        let single = expand(&root, &["src/*.ts"]);
+        let recursive = expand(&root, &["src/**/*.ts"]);
+        let braces = expand(&root, &["{src,other}/*.ts", "src/a.ts"]);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(single, ["src/a.ts"]);
+        assert_eq!(recursive, ["src/a.ts", "src/nested/c.ts"]);
+        // the base stops at the first glob component, inputs are deduplicated
+        assert_eq!(braces, ["other/d.ts", "src/a.ts"]);
+    }
+
+    #[test]
+    fn lintmasterignore_drops_walked_and_explicit_files() {
+        let root = tree("ignore", &["src/a.ts", "gen/b.ts", "gen/c.ts"]);
+        fs::write(root.join(IGNORE_FILE_NAME), "gen/\n").unwrap();
+        let walked = expand(&root, &["src", "gen"]);
+        let explicit = expand(&root, &["gen/b.ts", "src/a.ts"]);
+        let retained = FileDiscovery::retain_not_ignored(
+            vec![
+                root.join("gen/c.ts").to_string_lossy().into_owned(),
+                root.join("src/a.ts").to_string_lossy().into_owned(),
+            ],
+            &root,
+        );
+        let missing =
+            FileDiscovery::expand(&[root.join("x.ts").to_string_lossy().into_owned()], &root);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(walked, ["src/a.ts"]);
+        assert_eq!(explicit, ["src/a.ts"]);
+        assert_eq!(retained, [root.join("src/a.ts").to_string_lossy()]);
+        assert!(missing.is_err());
+    }
+}
@@ -0,0 +1,207 @@
+use crate::diagnostic::{Edit, Fix};
+use crate::error::LintError;
+use crate::git::Git;
+use crate::report::{FileFix, Report};
+use similar::TextDiff;
+use std::fs;
+use std::path::Path;
+
+pub struct Fixer {}
+
+impl Fixer {
+    // applies the fixes of the reported problems, the fixed ones leave the report unless
+    // `dry_run` only previews them
+    pub fn run(report: &mut Report, staged: bool, dry_run: bool) -> Result<(), LintError> {
+        for file in report.files.iter_mut() {
+            // (check, diagnostic) indexes of the fixable problems
+            let fixable: Vec<(usize, usize)> = file
+                .checks
+                .iter()
+                .enumerate()
+                .flat_map(|(i, check)| {
+                    check
+                        .diagnostics
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, diagnostic)| diagnostic.fix.is_some())
+                        .map(move |(j, _)| (i, j))
+                })
+                .collect();
+            if fixable.is_empty() {
+                continue;
+            }
+
+            // the offsets point into the checked contents
+            let before = if staged {
+                Git::read_index(&file.path)?
+            } else {
+                fs::read_to_string(&file.path).map_err(|e| LintError::io(&file.path, e))?
+            };
+            // fixing a file with unstaged changes would commit them along
+            if staged && !dry_run && fs::read_to_string(&file.path).ok().as_ref() != Some(&before) {
+                continue;
+            }
+
+            let fixes: Vec<&Fix> = fixable
+                .iter()
+                .filter_map(|&(i, j)| file.checks[i].diagnostics[j].fix.as_ref())
+                .collect();
+            let (after, applied) = Self::apply(&before, &fixes);
+            let fixed = applied.iter().filter(|&&applied| applied).count();
+            if fixed == 0 {
+                continue;
+            }
+
+            if !dry_run {
+                Self::write(&file.path, &after)?;
+                if staged {
+                    Git::add(&file.path)?;
+                }
+                for (&(i, j), _) in fixable.iter().zip(&applied).rev().filter(|(_, &a)| a) {
+                    file.checks[i].diagnostics.remove(j);
+                }
+            }
+
+            report.fixes.push(FileFix {
+                path: file.path.clone(),
+                fixed,
+                written: !dry_run,
+                diff: TextDiff::from_lines(&before, &after)
+                    .unified_diff()
+                    .context_radius(3)
+                    .header(&format!("a/{}", file.path), &format!("b/{}", file.path))
+                    .to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    // applies the fixes not overlapping an earlier one, returns the new contents and
+    // which fixes were applied; an edit equal to an applied one, like the same import,
+    // is shared instead of conflicting
+    fn apply(contents: &str, fixes: &[&Fix]) -> (String, Vec<bool>) {
+        let mut order: Vec<usize> = (0..fixes.len()).collect();
+        order.sort_by_key(|&i| fixes[i].edits.iter().map(|edit| edit.start).min());
+
+        let mut applied = vec![false; fixes.len()];
+        let mut edits: Vec<&Edit> = Vec::new();
+        for i in order {
+            let valid = fixes[i].edits.iter().all(|edit| {
+                edit.start <= edit.end
+                    && contents.is_char_boundary(edit.start)
+                    && contents.is_char_boundary(edit.end)
+                    && !edits
+                        .iter()
+                        .any(|&other| other != edit && Self::overlaps(edit, other))
+            });
+            if valid {
+                for edit in &fixes[i].edits {
+                    if !edits.contains(&edit) {
+                        edits.push(edit);
+                    }
+                }
+                applied[i] = true;
+            }
+        }
+
+        // from the end, so earlier offsets stay valid
+        edits.sort_by_key(|edit| (edit.start, edit.end));
+        let mut result = contents.to_string();
+        for edit in edits.iter().rev() {
+            result.replace_range(edit.start..edit.end, &edit.replacement);
+        }
+        (result, applied)
+    }
+
+    // two insertions at the same offset overlap too, their order would be arbitrary
+    fn overlaps(a: &Edit, b: &Edit) -> bool {
+        (a.start < b.end && b.start < a.end) || a.start == b.start
+    }
+
+    // writes next to the file then renames, so an interrupted run never leaves it half written
+    fn write(path: &str, contents: &str) -> Result<(), LintError> {
+        let target = Path::new(path);
+        let file_name = target
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let temp = target.with_file_name(format!(".{}.lint-master-fix", file_name));
+
+        fs::write(&temp, contents).map_err(|e| LintError::write(path, e))?;
+        if let Ok(metadata) = fs::metadata(target) {
+            let _ = fs::set_permissions(&temp, metadata.permissions());
+        }
+        fs::rename(&temp, target).map_err(|e| {
+            let _ = fs::remove_file(&temp);
+            LintError::write(path, e)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fix(edits: &[(usize, usize, &str)]) -> Fix {
+        Fix {
+            description: String::new(),
+            edits: edits
+                .iter()
+                .map(|&(start, end, replacement)| Edit::new(start, end, replacement.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn applies_fixes_in_any_order() {
+        let (first, second) = (fix(&[(6, 7, "y")]), fix(&[(0, 5, "let")]));
+        let (result, applied) = Fixer::apply("const x = 1;", &[&first, &second]);
+
+        assert_eq!(result, "let y = 1;");
+        assert_eq!(applied, vec![true, true]);
+    }
+
+    #[test]
+    fn skips_fixes_overlapping_an_earlier_one() {
+        let (first, second) = (fix(&[(0, 7, "let y")]), fix(&[(6, 7, "z")]));
+        let (result, applied) = Fixer::apply("const x = 1;", &[&first, &second]);
+
+        assert_eq!(result, "let y = 1;");
+        assert_eq!(applied, vec![true, false]);
+    }
+
+    #[test]
+    fn skips_insertions_at_the_same_offset() {
+        let (first, second) = (fix(&[(0, 0, "a")]), fix(&[(0, 0, "b")]));
+        let (result, applied) = Fixer::apply("x", &[&first, &second]);
+
+        assert_eq!(result, "ax");
+        assert_eq!(applied, vec![true, false]);
+    }
+
+    #[test]
+    fn shares_identical_edits() {
+        // two colors replaced by tokens of the same theme import it once
+        let import = (0, 0, "import { theme } from './theme';\n");
+        let first = fix(&[(11, 20, "theme.a"), import]);
+        let second = fix(&[(22, 31, "theme.b"), import]);
+        let (result, applied) =
+            Fixer::apply("const c = ['#000000', '#ffffff'];", &[&first, &second]);
+
+        assert_eq!(
+            result,
+            "import { theme } from './theme';\nconst c = [theme.a, theme.b];"
+        );
+        assert_eq!(applied, vec![true, true]);
+    }
+
+    #[test]
+    fn skips_edits_outside_char_boundaries() {
+        let invalid = fix(&[(1, 2, "x")]);
+        let (result, applied) = Fixer::apply("é", &[&invalid]);
+
+        assert_eq!(result, "é");
+        assert_eq!(applied, vec![false]);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::report::Report;
+use crate::utils::escape_xml;
+use std::fmt::Write;
+
+// `--format checkstyle`, a `<file>` per checked file with an `<error>` per diagnostic
+pub struct Checkstyle {}
+
+impl Checkstyle {
+    pub fn render(report: &Report) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<checkstyle version=\"4.3\">\n");
+        for file in &report.files {
+            let _ = writeln!(xml, "  <file name=\"{}\">", escape_xml(&file.path));
+            for diagnostic in file.diagnostics() {
+                let _ = writeln!(
+                    xml,
+                    "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"{}\"/>",
+                    diagnostic.start.line,
+                    diagnostic.start.column,
+                    diagnostic.severity,
+                    escape_xml(&diagnostic.message),
+                    escape_xml(&diagnostic.qualified_rule_id())
+                );
+            }
+            xml.push_str("  </file>\n");
+        }
+        xml.push_str("</checkstyle>\n");
+        xml
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formatters::tests::report;
+
+    #[test]
+    fn escapes_messages_and_qualifies_sources() {
+        let xml = Checkstyle::render(&report());
+
+        assert!(xml.contains(
+            r#"<error line="2" column="7" severity="error" message="&apos;x&apos; is assigned a value but never used" source="eslint/no-unused-vars"/>"#
+        ));
+        assert!(xml.contains(
+            r#"message="has unresolved marker // TODO &lt;b&gt; &amp; &quot;c&quot;, d:e%" source="todo"/>"#
+        ));
+        assert!(xml.contains("  <file name=\"src/b.ts\">\n  </file>\n"));
+    }
+}
@@ -0,0 +1,119 @@
+use super::RepositoryPaths;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::report::Report;
+use crate::utils::to_hex;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+#[derive(Serialize)]
+struct Issue<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    description: &'a str,
+    check_name: String,
+    fingerprint: String,
+    severity: &'static str,
+    location: Location,
+}
+
+#[derive(Serialize)]
+struct Location {
+    path: String,
+    lines: Lines,
+}
+
+#[derive(Serialize)]
+struct Lines {
+    begin: usize,
+    end: usize,
+}
+
+// `--format codeclimate`, the Code Climate issues GitLab shows as Code Quality in
+// merge requests
+pub struct CodeClimate {}
+
+impl CodeClimate {
+    pub fn render(report: &Report) -> String {
+        let paths = RepositoryPaths::new();
+        let issues: Vec<Issue> = report
+            .diagnostics()
+            .map(|diagnostic| {
+                let path = paths.path(&diagnostic.path);
+                let check_name = diagnostic.qualified_rule_id();
+                Issue {
+                    kind: "issue",
+                    description: &diagnostic.message,
+                    fingerprint: Self::fingerprint(&path, &check_name, diagnostic),
+                    check_name,
+                    severity: match diagnostic.severity {
+                        Severity::Error => "major",
+                        Severity::Warning => "minor",
+                        Severity::Info => "info",
+                    },
+                    location: Location {
+                        path,
+                        lines: Lines {
+                            begin: diagnostic.start.line,
+                            end: diagnostic.end.line.max(diagnostic.start.line),
+                        },
+                    },
+                }
+            })
+            .collect();
+        serde_json::to_string_pretty(&issues).expect("report serializes") + "\n"
+    }
+
+    // the diagnostic fingerprint leaves out the line number, so an issue keeps it when
+    // lines above it move; the path tells apart the same problem in two files
+    fn fingerprint(path: &str, check_name: &str, diagnostic: &Diagnostic) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(path);
+        hasher.update([0]);
+        hasher.update(check_name);
+        hasher.update([0]);
+        hasher.update(&diagnostic.fingerprint);
+        to_hex(&hasher.finalize()[..16])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::Position;
+    use crate::formatters::tests::report;
+    use serde_json::Value;
+
+    #[test]
+    fn fingerprints_ignore_line_shifts() {
+        let report = report();
+        let diagnostic = report.diagnostics().next().unwrap();
+        let moved = diagnostic
+            .clone()
+            .at(Position::new(12, 7), Position::new(12, 8));
+        let fingerprint = CodeClimate::fingerprint("src/a.ts", "eslint/no-unused-vars", diagnostic);
+
+        assert_eq!(fingerprint.len(), 32);
+        assert_eq!(
+            fingerprint,
+            CodeClimate::fingerprint("src/a.ts", "eslint/no-unused-vars", &moved)
+        );
+        assert_ne!(
+            fingerprint,
+            CodeClimate::fingerprint("src/b.ts", "eslint/no-unused-vars", diagnostic)
+        );
+    }
+
+    #[test]
+    fn renders_issues() {
+        let issues: Value = serde_json::from_str(&CodeClimate::render(&report())).unwrap();
+
+        assert_eq!(issues.as_array().unwrap().len(), 2);
+        assert_eq!(issues[0]["check_name"], "eslint/no-unused-vars");
+        assert_eq!(issues[0]["severity"], "major");
+        assert_eq!(issues[1]["severity"], "minor");
+        assert_eq!(
+            issues[1]["location"]["lines"],
+            serde_json::json!({ "begin": 3, "end": 4 })
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use super::RepositoryPaths;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::report::Report;
+use crate::utils::status_emoji;
+use std::fmt::Write;
+
+// `--format github`, workflow commands GitHub Actions turns into annotations, and a
+// Markdown summary for the job page
+pub struct Github {}
+
+impl Github {
+    pub fn render(report: &Report) -> String {
+        let paths = RepositoryPaths::new();
+        let mut log = String::new();
+        for diagnostic in report.diagnostics() {
+            log.push_str(&Self::command(diagnostic, &paths.path(&diagnostic.path)));
+        }
+
+        for fix in &report.fixes {
+            if fix.written {
+                let _ = writeln!(log, "Fixed {} in {}", fix.fixed, fix.path);
+            } else {
+                // the diff folds away in the log
+                let _ = writeln!(log, "::group::Would fix {} in {}", fix.fixed, fix.path);
+                log.push_str(&fix.diff);
+                if !fix.diff.ends_with('\n') {
+                    log.push('\n');
+                }
+                log.push_str("::endgroup::\n");
+            }
+        }
+
+        let _ = writeln!(
+            log,
+            "All errors total {}, warnings total {}",
+            report.errors(),
+            report.warnings()
+        );
+        if report.baselined > 0 {
+            let _ = writeln!(log, "Baseline problems left out {}", report.baselined);
+        }
+        if !report.fixed.is_empty() {
+            let _ = writeln!(
+                log,
+                "::notice title=baseline::{} baseline entries fixed, run `lint_master baseline` to drop them",
+                report.fixed.len()
+            );
+        }
+        log
+    }
+
+    // `::error file=a.tsx,line=2,col=7,title=eslint/no-unused-vars::message`
+    fn command(diagnostic: &Diagnostic, path: &str) -> String {
+        let level = match diagnostic.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "notice",
+        };
+        let (start, end) = (diagnostic.start, diagnostic.end);
+        let mut properties = format!(
+            "file={},line={},col={}",
+            Self::escape_property(path),
+            start.line,
+            start.column
+        );
+        // GitHub only takes an end column on a single line
+        if end.line > start.line {
+            properties.push_str(&format!(",endLine={}", end.line));
+        } else if end.line == start.line && end.column > start.column {
+            properties.push_str(&format!(",endColumn={}", end.column));
+        }
+        properties.push_str(&format!(
+            ",title={}",
+            Self::escape_property(&diagnostic.qualified_rule_id())
+        ));
+
+        let mut message = diagnostic.message.clone();
+        if let Some(suggestion) = &diagnostic.suggestion {
+            message.push_str(&format!("\n{}", suggestion));
+        }
+        format!(
+            "::{} {}::{}\n",
+            level,
+            properties,
+            Self::escape_data(&message)
+        )
+    }
+
+    // the job summary, a row per rule with problems like the rows of the tables
+    pub fn summary(report: &Report) -> String {
+        let paths = RepositoryPaths::new();
+        let errors = report.errors();
+        let warnings = report.warnings();
+        let mut markdown = format!(
+            "### {}lint_master\n\n{} errors, {} warnings in {} files\n\n",
+            status_emoji(errors, warnings),
+            errors,
+            warnings,
+            report.files.len()
+        );
+
+        let rows: Vec<String> = report
+            .files
+            .iter()
+            .flat_map(|file| {
+                let path = paths.path(&file.path);
+                file.checks
+                    .iter()
+                    .filter(|check| !check.diagnostics.is_empty())
+                    .map(move |check| {
+                        let errors = check.count(Severity::Error);
+                        let warnings = check.count(Severity::Warning);
+                        format!(
+                            "| {} | {} | {} | {} | {} |\n",
+                            status_emoji(errors, warnings).trim_end(),
+                            Self::escape_cell(&path),
+                            Self::escape_cell(&check.label),
+                            errors,
+                            warnings
+                        )
+                    })
+            })
+            .collect();
+        if rows.is_empty() {
+            markdown.push_str("No problems found.\n");
+        } else {
+            markdown.push_str("| Status | File | Rule | Errors | Warnings |\n");
+            markdown.push_str("| --- | --- | --- | ---: | ---: |\n");
+            markdown.push_str(&rows.concat());
+        }
+
+        for fix in &report.fixes {
+            let verb = if fix.written { "Fixed" } else { "Would fix" };
+            let _ = write!(
+                markdown,
+                "\n{} {} in `{}`\n",
+                verb,
+                fix.fixed,
+                fix.path.replace('`', "'")
+            );
+        }
+        if report.baselined > 0 {
+            let _ = write!(
+                markdown,
+                "\nBaseline problems left out {}\n",
+                report.baselined
+            );
+        }
+        if !report.fixed.is_empty() {
+            let _ = write!(
+                markdown,
+                "\nBaseline entries fixed {}, run `lint_master baseline` to drop them\n",
+                report.fixed.len()
+            );
+        }
+        markdown
+    }
+
+    fn escape_data(text: &str) -> String {
+        text.replace('%', "%25")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+    }
+
+    fn escape_property(text: &str) -> String {
+        Self::escape_data(text)
+            .replace(':', "%3A")
+            .replace(',', "%2C")
+    }
+
+    fn escape_cell(text: &str) -> String {
+        text.replace('|', "\\|").replace('\n', " ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formatters::tests::report;
+
+    #[test]
+    fn commands_escape_properties_and_give_end_positions() {
+        let report = report();
+        let diagnostics: Vec<&Diagnostic> = report.diagnostics().collect();
+
+        // a single line gets an end column, several lines an end line
+        assert_eq!(
+            Github::command(diagnostics[0], "src/a.ts"),
+            "::error file=src/a.ts,line=2,col=7,endColumn=8,title=eslint/no-unused-vars::'x' is assigned a value but never used\n"
+        );
+        assert_eq!(
+            Github::command(diagnostics[1], "src/a,b:c.ts"),
+            "::warning file=src/a%2Cb%3Ac.ts,line=3,col=1,endLine=4,title=todo::has unresolved marker // TODO <b> & \"c\", d:e%25%0Aresolve it\n"
+        );
+    }
+
+    #[test]
+    fn summary_lists_rules_with_problems() {
+        let summary = Github::summary(&report());
+
+        assert!(summary.contains("1 errors, 1 warnings in 2 files"));
+        assert!(summary.contains("| 🔴 | src/a.ts | ESLINT | 1 | 0 |\n"));
+        assert!(summary.contains("| 🟡 | src/a.ts | TODO | 0 | 1 |\n"));
+        assert!(!summary.contains("FILE-LINES"));
+        assert!(summary.contains("Baseline problems left out 1"));
+    }
+}
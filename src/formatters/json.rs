@@ -0,0 +1,227 @@
+use crate::constants::JSON_SCHEMA_VERSION;
+use crate::diagnostic::{Diagnostic, Position, Severity};
+use crate::report::Report;
+use serde::Serialize;
+
+// `--format json`, the schema is versioned by `version` and only grows new fields
+// within a version
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    version: u32,
+    tool: JsonTool,
+    status: &'static str,
+    totals: JsonTotals,
+    files: Vec<JsonFile<'a>>,
+    fixes: Vec<JsonFileFix<'a>>,
+    baseline_fixed: Vec<JsonBaselineEntry<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonTool {
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Serialize)]
+struct JsonTotals {
+    files: usize,
+    errors: usize,
+    warnings: usize,
+    infos: usize,
+    fixed: usize,
+    baselined: usize,
+}
+
+#[derive(Serialize)]
+struct JsonFile<'a> {
+    path: &'a str,
+    status: &'static str,
+    errors: usize,
+    warnings: usize,
+    checks: Vec<JsonCheck<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonCheck<'a> {
+    rule_id: &'a str,
+    status: &'static str,
+    diagnostics: Vec<JsonDiagnostic<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    rule_id: &'a str,
+    // rule of the wrapped linter, e.g. `no-unused-vars` for eslint
+    code: Option<&'a str>,
+    severity: Severity,
+    message: &'a str,
+    start: Position,
+    end: Position,
+    suggestion: Option<&'a str>,
+    fingerprint: &'a str,
+    fix: Option<JsonFix<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonFix<'a> {
+    description: &'a str,
+    // byte offsets into the checked contents
+    edits: Vec<JsonEdit<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonEdit<'a> {
+    start: usize,
+    end: usize,
+    replacement: &'a str,
+}
+
+#[derive(Serialize)]
+struct JsonFileFix<'a> {
+    path: &'a str,
+    fixed: usize,
+    written: bool,
+}
+
+#[derive(Serialize)]
+struct JsonBaselineEntry<'a> {
+    path: &'a str,
+    rule_id: &'a str,
+    fingerprint: &'a str,
+}
+
+pub struct Json {}
+
+impl Json {
+    pub fn render(report: &Report) -> String {
+        let files = report
+            .files
+            .iter()
+            .map(|file| {
+                let errors = file.count(Severity::Error);
+                let warnings = file.count(Severity::Warning);
+                JsonFile {
+                    path: &file.path,
+                    status: Self::status(errors, warnings),
+                    errors,
+                    warnings,
+                    checks: file
+                        .checks
+                        .iter()
+                        .map(|check| JsonCheck {
+                            rule_id: &check.rule_id,
+                            status: Self::status(
+                                check.count(Severity::Error),
+                                check.count(Severity::Warning),
+                            ),
+                            diagnostics: check.diagnostics.iter().map(Self::diagnostic).collect(),
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        let json = JsonReport {
+            version: JSON_SCHEMA_VERSION,
+            tool: JsonTool {
+                name: env!("CARGO_PKG_NAME"),
+                version: env!("CARGO_PKG_VERSION"),
+            },
+            status: Self::status(report.errors(), report.warnings()),
+            totals: JsonTotals {
+                files: report.files.len(),
+                errors: report.errors(),
+                warnings: report.warnings(),
+                infos: report.count(Severity::Info),
+                fixed: report.fixes.iter().map(|fix| fix.fixed).sum(),
+                baselined: report.baselined,
+            },
+            files,
+            fixes: report
+                .fixes
+                .iter()
+                .map(|fix| JsonFileFix {
+                    path: &fix.path,
+                    fixed: fix.fixed,
+                    written: fix.written,
+                })
+                .collect(),
+            baseline_fixed: report
+                .fixed
+                .iter()
+                .map(|entry| JsonBaselineEntry {
+                    path: &entry.path,
+                    rule_id: &entry.rule_id,
+                    fingerprint: &entry.fingerprint,
+                })
+                .collect(),
+        };
+        serde_json::to_string_pretty(&json).expect("report serializes") + "\n"
+    }
+
+    fn diagnostic(diagnostic: &Diagnostic) -> JsonDiagnostic<'_> {
+        JsonDiagnostic {
+            rule_id: &diagnostic.rule_id,
+            code: diagnostic.code.as_deref(),
+            severity: diagnostic.severity,
+            message: &diagnostic.message,
+            start: diagnostic.start,
+            end: diagnostic.end,
+            suggestion: diagnostic.suggestion.as_deref(),
+            fingerprint: &diagnostic.fingerprint,
+            fix: diagnostic.fix.as_ref().map(|fix| JsonFix {
+                description: &fix.description,
+                edits: fix
+                    .edits
+                    .iter()
+                    .map(|edit| JsonEdit {
+                        start: edit.start,
+                        end: edit.end,
+                        replacement: &edit.replacement,
+                    })
+                    .collect(),
+            }),
+        }
+    }
+
+    // same levels as the status column of the table
+    fn status(errors: usize, warnings: usize) -> &'static str {
+        if errors > 0 {
+            "error"
+        } else if warnings > 0 {
+            "warning"
+        } else {
+            "passed"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formatters::tests::report;
+    use serde_json::Value;
+
+    #[test]
+    fn renders_status_and_totals() {
+        let json: Value = serde_json::from_str(&Json::render(&report())).unwrap();
+
+        assert_eq!(json["version"], JSON_SCHEMA_VERSION);
+        assert_eq!(json["status"], "error");
+        assert_eq!(
+            json["totals"],
+            serde_json::json!({
+                "files": 2, "errors": 1, "warnings": 1, "infos": 0, "fixed": 0, "baselined": 1
+            })
+        );
+        assert_eq!(json["files"][0]["status"], "error");
+        assert_eq!(json["files"][0]["checks"][1]["status"], "warning");
+        assert_eq!(json["files"][1]["status"], "passed");
+        let diagnostic = &json["files"][0]["checks"][0]["diagnostics"][0];
+        assert_eq!(diagnostic["code"], "no-unused-vars");
+        assert_eq!(
+            diagnostic["start"],
+            serde_json::json!({ "line": 2, "column": 7 })
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::report::{FileReport, Report, RuleCheck};
+use crate::utils::escape_xml;
+use std::fmt::Write;
+
+// `--format junit`, a testsuite per file and a testcase per rule row of its table;
+// a row with errors or warnings fails with its diagnostics
+pub struct Junit {}
+
+impl Junit {
+    pub fn render(report: &Report) -> String {
+        let tests: usize = report.files.iter().map(|file| file.checks.len()).sum();
+        let failures: usize = report.files.iter().map(Self::failures).sum();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\">",
+            env!("CARGO_PKG_NAME"),
+            tests,
+            failures
+        );
+        for file in &report.files {
+            let _ = writeln!(
+                xml,
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\">",
+                escape_xml(&file.path),
+                file.checks.len(),
+                Self::failures(file)
+            );
+            for check in &file.checks {
+                Self::testcase(&mut xml, file, check);
+            }
+            xml.push_str("  </testsuite>\n");
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+
+    fn testcase(xml: &mut String, file: &FileReport, check: &RuleCheck) {
+        let _ = write!(
+            xml,
+            "    <testcase name=\"{}\" classname=\"{}\"",
+            escape_xml(&check.rule_id),
+            escape_xml(&file.path)
+        );
+        if check.diagnostics.is_empty() {
+            xml.push_str("/>\n");
+            return;
+        }
+        xml.push_str(">\n");
+
+        let errors = check.count(Severity::Error);
+        let warnings = check.count(Severity::Warning);
+        let body: Vec<String> = check.diagnostics.iter().map(Self::line).collect();
+        let body = escape_xml(&body.join("\n"));
+        if errors > 0 || warnings > 0 {
+            let _ = writeln!(
+                xml,
+                "      <failure message=\"{} errors, {} warnings\" type=\"{}\">{}</failure>",
+                errors,
+                warnings,
+                if errors > 0 { "error" } else { "warning" },
+                body
+            );
+        } else {
+            // infos only, the row passes
+            let _ = writeln!(xml, "      <system-out>{}</system-out>", body);
+        }
+        xml.push_str("    </testcase>\n");
+    }
+
+    // `path:line:column severity message (suggestion)`
+    fn line(diagnostic: &Diagnostic) -> String {
+        let mut line = format!(
+            "{}:{}:{} {} {}",
+            diagnostic.path,
+            diagnostic.start.line,
+            diagnostic.start.column,
+            diagnostic.severity,
+            diagnostic.message
+        );
+        if let Some(suggestion) = &diagnostic.suggestion {
+            line.push_str(&format!(" ({})", suggestion));
+        }
+        line
+    }
+
+    // rule rows that fail
+    fn failures(file: &FileReport) -> usize {
+        file.checks
+            .iter()
+            .filter(|check| check.count(Severity::Error) + check.count(Severity::Warning) > 0)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formatters::tests::report;
+
+    #[test]
+    fn counts_failing_rule_rows() {
+        let xml = Junit::render(&report());
+
+        assert!(
+            xml.contains(r#"<testsuites name="lint_master" tests="4" failures="2" errors="0">"#)
+        );
+        assert!(xml.contains(
+            r#"<testsuite name="src/a.ts" tests="3" failures="2" errors="0" skipped="0">"#
+        ));
+        assert!(xml.contains(
+            r#"<testsuite name="src/b.ts" tests="1" failures="0" errors="0" skipped="0">"#
+        ));
+        assert!(xml.contains(r#"<testcase name="file-lines" classname="src/a.ts"/>"#));
+        assert!(xml.contains(r#"<failure message="1 errors, 0 warnings" type="error">"#));
+        assert!(xml.contains(r#"<failure message="0 errors, 1 warnings" type="warning">"#));
+    }
+}
@@ -0,0 +1,127 @@
+mod checkstyle;
+mod code_climate;
+mod github;
+mod json;
+mod junit;
+mod sarif;
+
+pub use checkstyle::Checkstyle;
+pub use code_climate::CodeClimate;
+pub use github::Github;
+pub use json::Json;
+pub use junit::Junit;
+pub use sarif::Sarif;
+
+use crate::git::Git;
+use std::fs;
+use std::path::PathBuf;
+
+// paths from the repository root, where CI services resolve report paths from
+struct RepositoryPaths {
+    // canonical repository root, None outside a repository
+    toplevel: Option<PathBuf>,
+}
+
+impl RepositoryPaths {
+    fn new() -> RepositoryPaths {
+        RepositoryPaths {
+            toplevel: Git::toplevel()
+                .ok()
+                .and_then(|toplevel| fs::canonicalize(toplevel).ok()),
+        }
+    }
+
+    // `../src/b.ts` from `app/` is `src/b.ts`, paths resolving outside the repository
+    // are kept
+    fn path(&self, path: &str) -> String {
+        let relative = self.toplevel.as_ref().and_then(|toplevel| {
+            let path = fs::canonicalize(path).ok()?;
+            Some(path.strip_prefix(toplevel).ok()?.to_path_buf())
+        });
+        match relative {
+            Some(relative) => relative.to_string_lossy().replace('\\', "/"),
+            None => path.trim_start_matches("./").replace('\\', "/"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::{Diagnostic, Edit, Position, Severity};
+    use crate::report::{FileReport, Report, RuleCheck};
+
+    fn check(rule_id: &str, diagnostics: Vec<Diagnostic>) -> RuleCheck {
+        RuleCheck {
+            rule_id: rule_id.to_string(),
+            label: rule_id.to_uppercase(),
+            found: diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.fingerprint.clone())
+                .collect(),
+            diagnostics,
+        }
+    }
+
+    // an ESLint error, a TODO warning with a fix and a file without problems
+    pub(super) fn report() -> Report {
+        let mut error = Diagnostic::new(
+            "eslint",
+            "src/a.ts",
+            "'x' is assigned a value but never used".to_string(),
+        )
+        .at(Position::new(2, 7), Position::new(2, 8))
+        .with_code("no-unused-vars");
+        error.fingerprint = "0f1e".to_string();
+        let mut warning = Diagnostic::new(
+            "todo",
+            "src/a.ts",
+            "has unresolved marker // TODO <b> & \"c\", d:e%".to_string(),
+        )
+        .at(Position::new(3, 1), Position::new(4, 2))
+        .with_severity(Severity::Warning)
+        .with_suggestion("resolve it".to_string())
+        .with_fix(
+            "remove the marker".to_string(),
+            vec![Edit::new(20, 27, String::new())],
+        );
+        warning.fingerprint = "2d3c".to_string();
+
+        Report {
+            files: vec![
+                FileReport {
+                    path: "src/a.ts".to_string(),
+                    checks: vec![
+                        check("eslint", vec![error]),
+                        check("todo", vec![warning]),
+                        check("file-lines", Vec::new()),
+                    ],
+                },
+                FileReport {
+                    path: "src/b.ts".to_string(),
+                    checks: vec![check("todo", Vec::new())],
+                },
+            ],
+            baselined: 1,
+            ..Report::default()
+        }
+    }
+
+    #[test]
+    fn paths_are_relative_to_the_toplevel() {
+        let root = std::env::temp_dir().join(format!("lint-master-paths-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/b.ts"), "").unwrap();
+        let root = fs::canonicalize(root).unwrap();
+        let paths = RepositoryPaths {
+            toplevel: Some(root.clone()),
+        };
+        let nested = root.join("src/../src/b.ts").to_string_lossy().into_owned();
+        let path = paths.path(&nested);
+        let missing = paths.path("./gone/a.ts");
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(path, "src/b.ts");
+        assert_eq!(missing, "gone/a.ts");
+    }
+}
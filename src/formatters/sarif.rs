@@ -0,0 +1,209 @@
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::report::Report;
+use crate::rules::Registry;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::env;
+
+// `--format sarif`, a SARIF 2.1.0 log with one run
+pub struct Sarif {}
+
+impl Sarif {
+    pub fn render(report: &Report, registry: &Registry) -> String {
+        // every built-in rule, then the wrapped eslint and golangci-lint rules that reported
+        let mut rules: BTreeMap<String, Value> = registry
+            .rules()
+            .map(|rule| {
+                let descriptor = json!({
+                    "id": rule.id(),
+                    "shortDescription": { "text": rule.description() },
+                    "fullDescription": { "text": rule.description() },
+                    "help": { "text": rule.help() },
+                    "defaultConfiguration": { "level": Self::level(rule.default_severity()) },
+                });
+                (rule.id().to_string(), descriptor)
+            })
+            .collect();
+        let mut ids: Vec<String> = rules.keys().cloned().collect();
+
+        let mut results = Vec::new();
+        for diagnostic in report.diagnostics() {
+            let id = diagnostic.qualified_rule_id();
+            if !rules.contains_key(&id) {
+                rules.insert(id.clone(), Self::wrapped_rule(&id, diagnostic, registry));
+                ids.push(id.clone());
+            }
+            let index = ids.iter().position(|other| *other == id).unwrap();
+            results.push(Self::result(diagnostic, &id, index));
+        }
+
+        let root = env::current_dir()
+            .map(|cwd| format!("file://{}/", Self::encode(&cwd.to_string_lossy())))
+            .unwrap_or_default();
+        let sarif = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": env!("CARGO_PKG_NAME"),
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": ids.iter().map(|id| &rules[id]).collect::<Vec<_>>(),
+                    },
+                },
+                "originalUriBaseIds": { "%SRCROOT%": { "uri": root } },
+                "columnKind": "unicodeCodePoints",
+                "results": results,
+            }],
+        });
+        serde_json::to_string_pretty(&sarif).expect("sarif serializes") + "\n"
+    }
+
+    fn wrapped_rule(id: &str, diagnostic: &Diagnostic, registry: &Registry) -> Value {
+        let (text, help, help_uri) = match (diagnostic.rule_id.as_str(), &diagnostic.code) {
+            ("eslint", Some(code)) => (
+                format!("ESLint rule {}", code),
+                "Reported by ESLint, see its documentation.".to_string(),
+                // plugin rules like `react/no-danger` are documented by their plugin
+                (!code.contains('/'))
+                    .then(|| format!("https://eslint.org/docs/latest/rules/{}", code)),
+            ),
+            ("golangci-lint", Some(code)) => (
+                format!("golangci-lint linter {}", code),
+                "Reported by golangci-lint, see its documentation.".to_string(),
+                Some("https://golangci-lint.run/usage/linters/".to_string()),
+            ),
+            _ => (
+                registry
+                    .get(&diagnostic.rule_id)
+                    .map(|rule| rule.description().to_string())
+                    .unwrap_or_else(|| diagnostic.rule_id.clone()),
+                String::new(),
+                None,
+            ),
+        };
+
+        let mut descriptor = json!({
+            "id": id,
+            "shortDescription": { "text": text },
+            "help": { "text": help },
+        });
+        if let Some(help_uri) = help_uri {
+            descriptor["helpUri"] = json!(help_uri);
+        }
+        descriptor
+    }
+
+    fn result(diagnostic: &Diagnostic, id: &str, index: usize) -> Value {
+        let artifact = json!({
+            "uri": Self::encode(diagnostic.path.trim_start_matches("./")),
+            "uriBaseId": "%SRCROOT%",
+        });
+        let mut result = json!({
+            "ruleId": id,
+            "ruleIndex": index,
+            "level": Self::level(diagnostic.severity),
+            "message": { "text": diagnostic.message },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": artifact,
+                    "region": {
+                        "startLine": diagnostic.start.line,
+                        "startColumn": diagnostic.start.column,
+                        "endLine": diagnostic.end.line,
+                        "endColumn": diagnostic.end.column,
+                    },
+                },
+            }],
+            "partialFingerprints": { "lintMaster/v1": diagnostic.fingerprint },
+        });
+        if let Some(suggestion) = &diagnostic.suggestion {
+            result["properties"] = json!({ "suggestion": suggestion });
+        }
+        if let Some(fix) = &diagnostic.fix {
+            // edits are byte offsets into the checked contents
+            let replacements: Vec<Value> = fix
+                .edits
+                .iter()
+                .map(|edit| {
+                    json!({
+                        "deletedRegion": {
+                            "byteOffset": edit.start,
+                            "byteLength": edit.end - edit.start,
+                        },
+                        "insertedContent": { "text": edit.replacement },
+                    })
+                })
+                .collect();
+            result["fixes"] = json!([{
+                "description": { "text": fix.description },
+                "artifactChanges": [{
+                    "artifactLocation": artifact,
+                    "replacements": replacements,
+                }],
+            }]);
+        }
+        result
+    }
+
+    fn level(severity: Severity) -> &'static str {
+        match severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "note",
+        }
+    }
+
+    // percent-encodes a path for a URI, `/` separates segments
+    fn encode(path: &str) -> String {
+        let mut result = String::new();
+        for byte in path.replace('\\', "/").bytes() {
+            if byte.is_ascii_alphanumeric() || b"-._~/:".contains(&byte) {
+                result.push(byte as char);
+            } else {
+                result.push_str(&format!("%{:02X}", byte));
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formatters::tests::report;
+
+    #[test]
+    fn results_point_at_their_rule_and_carry_fixes() {
+        let sarif: Value =
+            serde_json::from_str(&Sarif::render(&report(), &Registry::builtin())).unwrap();
+        let run = &sarif["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        let results = run["results"].as_array().unwrap();
+
+        assert_eq!(results.len(), 2);
+        for result in results {
+            let index = result["ruleIndex"].as_u64().unwrap() as usize;
+            assert_eq!(rules[index]["id"], result["ruleId"]);
+        }
+        // the wrapped ESLint rule gets its own descriptor after the builtin rules
+        assert_eq!(results[0]["ruleId"], "eslint/no-unused-vars");
+        assert_eq!(results[0]["ruleIndex"], Registry::builtin().rules().count());
+        assert!(results[0].get("fixes").is_none());
+
+        let fix = &results[1]["fixes"][0];
+        assert_eq!(fix["description"]["text"], "remove the marker");
+        let replacement = &fix["artifactChanges"][0]["replacements"][0];
+        assert_eq!(
+            replacement["deletedRegion"],
+            json!({ "byteOffset": 20, "byteLength": 7 })
+        );
+        assert_eq!(replacement["insertedContent"]["text"], "");
+        assert_eq!(results[1]["level"], "warning");
+    }
+
+    #[test]
+    fn encode_escapes_uri_characters() {
+        assert_eq!(Sarif::encode("src/a b#.ts"), "src/a%20b%23.ts");
+    }
+}
@@ -0,0 +1,166 @@
+use crate::error::LintError;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+pub struct Git {}
+
+impl Git {
+    fn run(args: &[&str]) -> Result<Output, LintError> {
+        Command::new("git")
+            .args(args)
+            .output()
+            .map_err(|e| LintError::command("git", e))
+    }
+
+    fn is_repository() -> Result<bool, LintError> {
+        Ok(Self::run(&["rev-parse", "--git-dir"])?.status.success())
+    }
+
+    // `.git` directory of the current repository, None outside of a repository
+    pub fn git_dir() -> Result<Option<PathBuf>, LintError> {
+        let output = Self::run(&["rev-parse", "--git-dir"])?;
+        if !output.status.success() {
+            return Ok(None);
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(Some(PathBuf::from(stdout.trim())))
+    }
+
+    // hooks directory, honoring core.hooksPath
+    pub fn hooks_dir() -> Result<PathBuf, LintError> {
+        let output = Self::run(&["rev-parse", "--git-path", "hooks"])?;
+        if !output.status.success() {
+            return Err(LintError::Git(
+                "hooks can only be installed inside a git repository".to_string(),
+            ));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(PathBuf::from(stdout.trim()))
+    }
+
+    // staged paths with one of the `--diff-filter` statuses, relative to the current directory
+    fn staged_paths(diff_filter: &str) -> Result<Vec<String>, LintError> {
+        let filter = format!("--diff-filter={}", diff_filter);
+        let output = Self::run(&["diff", "--cached", "--name-only", "-z", &filter])?;
+        if !output.status.success() {
+            return Ok(Vec::new());
+        }
+
+        // git prints paths relative to the repository root
+        let cdup_output = Self::run(&["rev-parse", "--show-cdup"])?;
+        let cdup = String::from_utf8_lossy(&cdup_output.stdout)
+            .trim()
+            .to_string();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        Ok(stdout
+            .split('\0')
+            .filter(|path| !path.is_empty())
+            .map(|path| format!("{}{}", cdup, path))
+            .collect())
+    }
+
+    // files newly added to the index
+    pub fn added_files() -> Result<Vec<String>, LintError> {
+        Self::staged_paths("A")
+    }
+
+    // files added, copied, modified or renamed in the index
+    pub fn staged_files() -> Result<Vec<String>, LintError> {
+        if !Self::is_repository()? {
+            return Err(LintError::Git(
+                "--staged needs to run inside a git repository".to_string(),
+            ));
+        }
+        Self::staged_paths("ACMR")
+    }
+
+    // contents of `path` as staged in the index
+    pub fn read_index(path: &str) -> Result<String, LintError> {
+        let object = format!(":./{}", path.trim_start_matches("./"));
+        let output = Self::run(&["show", &object])?;
+        if !output.status.success() {
+            return Err(LintError::Git(format!(
+                "{} is not staged: {}",
+                path,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    // top level directory of the current repository
+    pub fn toplevel() -> Result<PathBuf, LintError> {
+        let output = Self::run(&["rev-parse", "--show-toplevel"])?;
+        if !output.status.success() {
+            return Err(LintError::Git(
+                "--changed-lines needs to run inside a git repository".to_string(),
+            ));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(PathBuf::from(stdout.trim()))
+    }
+
+    // zero context diff of the index (`cached`) or the working tree against `base`
+    pub fn diff(base: &str, cached: bool) -> Result<String, LintError> {
+        let mut args = vec![
+            "diff",
+            "-U0",
+            "--no-color",
+            "--no-ext-diff",
+            "--src-prefix=a/",
+            "--dst-prefix=b/",
+        ];
+        if cached {
+            args.push("--cached");
+        }
+        args.extend([base, "--"]);
+
+        let output = Self::run(&args)?;
+        if !output.status.success() {
+            return Err(LintError::Git(format!(
+                "git diff failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    // files not tracked by git and not ignored, relative to the repository root
+    pub fn untracked_files() -> Result<Vec<String>, LintError> {
+        let output = Self::run(&[
+            "ls-files",
+            "--others",
+            "--exclude-standard",
+            "--full-name",
+            "-z",
+        ])?;
+        if !output.status.success() {
+            return Ok(Vec::new());
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .split('\0')
+            .filter(|path| !path.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
+    pub fn add(path: &str) -> Result<(), LintError> {
+        let output = Self::run(&["add", "--", path])?;
+        if !output.status.success() {
+            return Err(LintError::Git(format!(
+                "could not stage {}: {}",
+                path,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use crate::constants::{HOOK_CHAINED_SUFFIX, HOOK_MARKER, HOOK_SKIP_ENV};
+use crate::error::LintError;
+use crate::git::Git;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub struct Hook {}
+
+impl Hook {
+    fn paths() -> Result<(PathBuf, PathBuf), LintError> {
+        let hooks_dir = Git::hooks_dir()?;
+        let hook = hooks_dir.join("pre-commit");
+        let chained = hooks_dir.join(format!("pre-commit{}", HOOK_CHAINED_SUFFIX));
+        Ok((hook, chained))
+    }
+
+    fn is_installed(hook: &Path) -> bool {
+        fs::read_to_string(hook).is_ok_and(|contents| contents.contains(HOOK_MARKER))
+    }
+
+    // `fix` fixes and restages the staged files before checking them
+    fn script(fix: bool) -> String {
+        let program = env::current_exe()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|_| "lint_master".to_string());
+        format!(
+            r#"#!/bin/sh
+{marker}, remove it with `lint_master install-hook --uninstall`
+if [ -n "${skip}" ]; then
+  exit 0
+fi
+chained="$0{chained}"
+if [ -x "$chained" ]; then
+  "$chained" "$@" || exit $?
+fi
+# the binary that installed the hook, or the one on PATH once it moved
+program="{program}"
+if [ ! -x "$program" ]; then
+  program=lint_master
+fi
+exec "$program" check --staged{fix}
+"#,
+            marker = HOOK_MARKER,
+            skip = HOOK_SKIP_ENV,
+            chained = HOOK_CHAINED_SUFFIX,
+            program = program,
+            fix = if fix { " --fix" } else { "" },
+        )
+    }
+
+    // writes the pre-commit hook, an existing foreign hook is kept and run first
+    pub fn install(fix: bool) -> Result<PathBuf, LintError> {
+        let (hook, chained) = Self::paths()?;
+        let display = hook.display().to_string();
+
+        if hook.exists() && !Self::is_installed(&hook) {
+            if chained.exists() {
+                return Err(LintError::Git(format!(
+                    "both {} and {} exist, remove one of them first",
+                    display,
+                    chained.display()
+                )));
+            }
+            fs::rename(&hook, &chained).map_err(|e| LintError::write(&display, e))?;
+        }
+
+        if let Some(dir) = hook.parent() {
+            fs::create_dir_all(dir).map_err(|e| LintError::write(&display, e))?;
+        }
+        fs::write(&hook, Self::script(fix)).map_err(|e| LintError::write(&display, e))?;
+        Self::make_executable(&hook)?;
+        Ok(hook)
+    }
+
+    // removes the pre-commit hook and restores the hook it chained
+    pub fn uninstall() -> Result<PathBuf, LintError> {
+        let (hook, chained) = Self::paths()?;
+        let display = hook.display().to_string();
+
+        if !Self::is_installed(&hook) {
+            return Err(LintError::Git(format!(
+                "{} is not a lint_master hook",
+                display
+            )));
+        }
+
+        fs::remove_file(&hook).map_err(|e| LintError::write(&display, e))?;
+        if chained.exists() {
+            fs::rename(&chained, &hook).map_err(|e| LintError::write(&display, e))?;
+        }
+        Ok(hook)
+    }
+
+    #[cfg(unix)]
+    fn make_executable(path: &Path) -> Result<(), LintError> {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+            .map_err(|e| LintError::write(&path.display().to_string(), e))
+    }
+
+    #[cfg(not(unix))]
+    fn make_executable(_path: &Path) -> Result<(), LintError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_falls_back_to_the_path() {
+        let script = Hook::script(true);
+        assert!(script.contains(HOOK_MARKER));
+        assert!(script.contains("program=lint_master\n"));
+        assert!(script.ends_with("exec \"$program\" check --staged --fix\n"));
+        assert!(Hook::script(false).ends_with("check --staged\n"));
+    }
+}
@@ -1,31 +1,234 @@
+pub mod baseline;
+mod cache;
+mod changed_lines;
 mod check_file;
+pub mod cli;
 mod constants;
+pub mod diagnostic;
 mod draw_table;
+pub mod error;
+mod file_discovery;
+mod fixer;
+mod formatters;
+mod git;
+mod hook;
+pub mod project_config;
+pub mod report;
+pub mod rules;
+mod suppression;
 mod utils;
+use crate::baseline::Baseline;
 use crate::check_file::CheckFile;
-use std::error::Error;
-use std::process;
+use crate::constants::{CONFIG_FILE_NAME, GITHUB_ACTIONS_ENV, GITHUB_STEP_SUMMARY_ENV};
+use crate::draw_table::DrawTable;
+use crate::error::LintError;
+use crate::fixer::Fixer;
+use crate::formatters::{Checkstyle, CodeClimate, Github, Json, Junit, Sarif};
+use crate::hook::Hook;
+use crate::project_config::ProjectConfig;
+use crate::report::Report;
+use crate::rules::Registry;
+use clap::ValueEnum;
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Sarif,
+    Junit,
+    Checkstyle,
+    Github,
+    // GitLab Code Quality
+    #[value(name = "codeclimate", alias = "gitlab")]
+    CodeClimate,
+}
+
+impl Format {
+    // the workflow commands when running in GitHub Actions, the tables otherwise
+    pub fn detect() -> Format {
+        if env::var(GITHUB_ACTIONS_ENV).is_ok_and(|value| value == "true") {
+            Format::Github
+        } else {
+            Format::Table
+        }
+    }
+}
+
+#[derive(Clone, Default)]
 pub struct Config {
+    // files, directories or globs
     pub file_paths: Vec<String>,
+    // explicit lint-master.toml, discovered from the current directory when None
+    pub config_file: Option<PathBuf>,
+    pub format: Format,
+    // write the report to this file instead of stdout, machine readable formats only
+    pub output: Option<PathBuf>,
+    // only run these rule ids, every enabled rule when empty
+    pub rules: Vec<String>,
+    // fail once there are more warnings, warnings never fail when None
+    pub max_warnings: Option<usize>,
+    // worker threads, the number of CPUs when None
+    pub jobs: Option<usize>,
+    // reuse and store rule results in .git/lint-master/cache.json
+    pub cache: bool,
+    // check the contents staged in the git index instead of the working tree,
+    // every staged file when `file_paths` is empty
+    pub staged: bool,
+    // only report diagnostics on lines changed compared to `diff_base`, or HEAD when None
+    pub changed_lines: bool,
+    pub diff_base: Option<String>,
+    // leave out the problems recorded in the baseline file
+    pub baseline: bool,
+    // lint-master-baseline.json next to lint-master.toml when None
+    pub baseline_file: Option<PathBuf>,
+    // apply the fixes of the reported problems, the fixed ones leave the report
+    pub fix: bool,
+    // with `fix`, only compute the diffs of the changes without writing them
+    pub dry_run: bool,
+}
+
+pub fn check(config: Config) -> Result<Report, LintError> {
+    check_with_registry(config, &Registry::builtin())
+}
+
+pub fn check_with_registry(config: Config, registry: &Registry) -> Result<Report, LintError> {
+    let project = load_project(config.config_file.as_deref(), registry)?;
+    for id in &config.rules {
+        if registry.get(id).is_none() {
+            return Err(LintError::Config(format!("unknown rule `{}`", id)));
+        }
+    }
+    let mut report = CheckFile::run(&config, registry, &project)?;
+    if config.baseline {
+        let path = baseline_path(&config, &project);
+        if let Some(baseline) = Baseline::load(&path)? {
+            baseline.apply(&mut report, &project);
+        }
+    }
+    if config.fix {
+        Fixer::run(&mut report, config.staged, config.dry_run)?;
+    }
+    Ok(report)
+}
+
+// records the problems found in the baseline file, entries of files and rules that were
+// not checked are kept; returns the file and its number of entries
+pub fn write_baseline(
+    config: Config,
+    registry: &Registry,
+) -> Result<(PathBuf, usize), LintError> {
+    let project = load_project(config.config_file.as_deref(), registry)?;
+    let report = check_with_registry(
+        Config {
+            baseline: false,
+            fix: false,
+            ..config.clone()
+        },
+        registry,
+    )?;
+    let path = baseline_path(&config, &project);
+    let baseline = Baseline::from_report(&report, &project, Baseline::load(&path)?);
+    let len = baseline.len();
+    baseline.save(&path)?;
+    Ok((path, len))
+}
+
+fn baseline_path(config: &Config, project: &ProjectConfig) -> PathBuf {
+    config
+        .baseline_file
+        .clone()
+        .unwrap_or_else(|| Baseline::default_path(project))
+}
+
+pub fn load_project(
+    config_file: Option<&Path>,
+    registry: &Registry,
+) -> Result<ProjectConfig, LintError> {
+    let project = match config_file {
+        Some(path) => ProjectConfig::load(path)?,
+        None => ProjectConfig::discover()?,
+    };
+    project.validate(registry)?;
+    Ok(project)
 }
 
-impl Config {
-    pub fn build(args: &[String]) -> Result<Config, &'static str> {
-        if args.len() < 2 {
-            eprintln!("Not enough arguments");
-            std::process::exit(1);
+// prints the report, or writes it to `output`
+pub fn print_report(
+    report: &Report,
+    registry: &Registry,
+    format: Format,
+    output: Option<&Path>,
+) -> Result<(), LintError> {
+    let rendered = match format {
+        Format::Table | Format::Github if output.is_some() => {
+            return Err(LintError::Config(
+                "--output needs a machine readable --format".to_string(),
+            ))
+        }
+        Format::Table => {
+            DrawTable::draw_report(report);
+            return Ok(());
+        }
+        Format::Github => {
+            print!("{}", Github::render(report));
+            // the summary file is shared by the steps of the job, so it is appended to
+            if let Some(path) = env::var_os(GITHUB_STEP_SUMMARY_ENV) {
+                let mut file = fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&path)
+                    .map_err(|e| LintError::write(&path.to_string_lossy(), e))?;
+                file.write_all(Github::summary(report).as_bytes())
+                    .map_err(|e| LintError::write(&path.to_string_lossy(), e))?;
+            }
+            return Ok(());
+        }
+        Format::Json => Json::render(report),
+        Format::Sarif => Sarif::render(report, registry),
+        Format::Junit => Junit::render(report),
+        Format::Checkstyle => Checkstyle::render(report),
+        Format::CodeClimate => CodeClimate::render(report),
+    };
+
+    match output {
+        Some(path) => {
+            fs::write(path, rendered).map_err(|e| LintError::write(&path.display().to_string(), e))
+        }
+        None => {
+            print!("{}", rendered);
+            Ok(())
         }
-        let file_paths = args[1..].to_vec();
-        Ok(Config { file_paths })
     }
 }
 
-pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
-    let file_paths = config.file_paths;
-    if let Err(e) = CheckFile::run(file_paths) {
-        println!("Application error: {e}");
-        process::exit(1);
+pub fn print_rules(registry: &Registry, project: &ProjectConfig) {
+    DrawTable::draw_rules(registry, project);
+}
+
+// writes the default lint-master.toml into the current directory
+pub fn init(force: bool) -> Result<PathBuf, LintError> {
+    let path = PathBuf::from(CONFIG_FILE_NAME);
+    if path.exists() && !force {
+        return Err(LintError::Config(format!(
+            "{} already exists, use --force to overwrite it",
+            CONFIG_FILE_NAME
+        )));
     }
-    Ok(())
+    fs::write(&path, ProjectConfig::default_toml())
+        .map_err(|e| LintError::write(CONFIG_FILE_NAME, e))?;
+    Ok(path)
+}
+
+// installs the pre-commit hook running `lint_master check --staged`, with `--fix` when `fix`
+pub fn install_hook(fix: bool) -> Result<PathBuf, LintError> {
+    Hook::install(fix)
+}
+
+pub fn uninstall_hook() -> Result<PathBuf, LintError> {
+    Hook::uninstall()
 }
@@ -1,16 +1,79 @@
+use clap::Parser;
+use lint_master::cli::{Cli, Command};
+use lint_master::error::LintError;
+use lint_master::rules::Registry;
 use lint_master::Config;
-use std::env;
 use std::process;
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    let config = Config::build(&args).unwrap_or_else(|err| {
-        println!("Problem parsing arguments: {err}");
-        process::exit(1);
+    let cli = Cli::parse();
+    let command = cli.command.unwrap_or(Command::Check(cli.check));
+
+    let code = run(command).unwrap_or_else(|e| {
+        eprintln!("Application error: {e}");
+        1
     });
+    process::exit(code);
+}
 
-    if let Err(e) = lint_master::run(config) {
-        println!("Application error: {e}");
-        process::exit(1);
+fn run(command: Command) -> Result<i32, LintError> {
+    let registry = Registry::builtin();
+    match command {
+        Command::Check(args) => check(args.into(), &registry),
+        Command::Fix(args) => check(
+            Config {
+                fix: true,
+                ..args.into()
+            },
+            &registry,
+        ),
+        Command::Baseline(args) => {
+            let (path, len) = lint_master::write_baseline(args.into(), &registry)?;
+            println!("Wrote {} entries to {}", len, path.display());
+            Ok(0)
+        }
+        Command::Rules { config } => {
+            let project = lint_master::load_project(config.as_deref(), &registry)?;
+            lint_master::print_rules(&registry, &project);
+            Ok(0)
+        }
+        Command::Explain { rule } => match registry.get(&rule) {
+            Some(rule) => {
+                println!("{} ({})", rule.id(), rule.extensions().join(", "));
+                println!();
+                println!("{}", rule.description());
+                if !rule.help().is_empty() {
+                    println!();
+                    println!("{}", rule.help());
+                }
+                Ok(0)
+            }
+            None => Err(LintError::Config(format!("unknown rule `{}`", rule))),
+        },
+        Command::InstallHook { uninstall, fix } => {
+            if uninstall {
+                let path = lint_master::uninstall_hook()?;
+                println!("Removed {}", path.display());
+            } else {
+                let path = lint_master::install_hook(fix)?;
+                println!("Installed {}", path.display());
+            }
+            Ok(0)
+        }
+        Command::Init { force } => {
+            let path = lint_master::init(force)?;
+            println!("Wrote {}", path.display());
+            Ok(0)
+        }
     }
 }
+
+fn check(config: Config, registry: &Registry) -> Result<i32, LintError> {
+    let report = lint_master::check_with_registry(config.clone(), registry)?;
+    lint_master::print_report(&report, registry, config.format, config.output.as_deref())?;
+    Ok(if report.is_failure(config.max_warnings) {
+        1
+    } else {
+        0
+    })
+}
@@ -0,0 +1,402 @@
+use crate::constants::{
+    CONFIG_FILE_NAME, FILE_LINE, NECESSARY_CONSOLE_LOGGING, RE_TSX_THEME_FILE, TODO_IGNORE_SEARCH,
+    TODO_SEARCH,
+};
+use crate::diagnostic::Severity;
+use crate::error::LintError;
+use crate::rules::Registry;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectConfig {
+    pub rules: BTreeMap<String, RuleConfig>,
+    pub overrides: Vec<Override>,
+    // directory holding lint-master.toml, relative option paths resolve against it
+    #[serde(skip)]
+    pub root: PathBuf,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct RuleConfig {
+    pub enabled: Option<bool>,
+    pub severity: Option<Severity>,
+    // false reports every diagnostic of the rule with --changed-lines
+    pub line_scoped: Option<bool>,
+    #[serde(flatten)]
+    pub options: toml::Table,
+}
+
+impl RuleConfig {
+    fn merge(&mut self, other: &RuleConfig) {
+        if other.enabled.is_some() {
+            self.enabled = other.enabled;
+        }
+        if other.severity.is_some() {
+            self.severity = other.severity;
+        }
+        if other.line_scoped.is_some() {
+            self.line_scoped = other.line_scoped;
+        }
+        for (key, value) in &other.options {
+            self.options.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+// rule settings applied on top of the root ones for files matching `files`
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Override {
+    pub files: Vec<String>,
+    #[serde(default)]
+    pub rules: BTreeMap<String, RuleConfig>,
+    #[serde(skip)]
+    matcher: Option<GlobSet>,
+}
+
+impl Override {
+    fn compile(&mut self) -> Result<(), LintError> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &self.files {
+            // like `--paths`, `*` stays within a directory and `**` recurses
+            let glob = GlobBuilder::new(pattern)
+                .literal_separator(true)
+                .build()
+                .map_err(|e| {
+                    LintError::Config(format!("invalid override glob `{}`: {}", pattern, e))
+                })?;
+            builder.add(glob);
+        }
+        let matcher = builder
+            .build()
+            .map_err(|e| LintError::Config(format!("invalid override globs: {}", e)))?;
+        self.matcher = Some(matcher);
+        Ok(())
+    }
+
+    fn matches(&self, relative_path: &Path) -> bool {
+        self.matcher
+            .as_ref()
+            .is_some_and(|matcher| matcher.is_match(relative_path))
+    }
+}
+
+pub struct ResolvedRule {
+    pub enabled: bool,
+    pub severity: Option<Severity>,
+    pub line_scoped: Option<bool>,
+    pub options: RuleOptions,
+}
+
+impl ProjectConfig {
+    // walks up from the current directory to the repository root looking for lint-master.toml
+    pub fn discover() -> Result<ProjectConfig, LintError> {
+        let cwd = env::current_dir().map_err(|e| LintError::io(".", e))?;
+        for dir in cwd.ancestors() {
+            let candidate = dir.join(CONFIG_FILE_NAME);
+            if candidate.is_file() {
+                return Self::load(&candidate);
+            }
+            if dir.join(".git").exists() {
+                break;
+            }
+        }
+
+        Ok(ProjectConfig {
+            root: fs::canonicalize(&cwd).unwrap_or(cwd),
+            ..ProjectConfig::default()
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<ProjectConfig, LintError> {
+        let display = path.display().to_string();
+        let contents = fs::read_to_string(path).map_err(|e| LintError::io(&display, e))?;
+        let mut config: ProjectConfig = toml::from_str(&contents)
+            .map_err(|e| LintError::Config(format!("invalid {}: {}", display, e)))?;
+        let parent = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        config.root = fs::canonicalize(parent).map_err(|e| LintError::io(&display, e))?;
+        for item in config.overrides.iter_mut() {
+            item.compile()?;
+        }
+        Ok(config)
+    }
+
+    pub fn validate(&self, registry: &Registry) -> Result<(), LintError> {
+        let override_rules = self.overrides.iter().flat_map(|item| &item.rules);
+        for (id, rule_config) in self.rules.iter().chain(override_rules) {
+            let rule = registry.get(id).ok_or_else(|| {
+                LintError::Config(format!("unknown rule `{}` in {}", id, CONFIG_FILE_NAME))
+            })?;
+            // a misspelled key would otherwise be taken for an option and do nothing
+            if let Some(key) = rule_config
+                .options
+                .keys()
+                .find(|key| !rule.options().contains(&key.as_str()))
+            {
+                let mut expected = vec!["enabled", "severity", "line_scoped"];
+                expected.extend(rule.options());
+                return Err(LintError::Config(format!(
+                    "unknown option `{}` of rule `{}` in {}, expected one of: {}",
+                    key,
+                    id,
+                    CONFIG_FILE_NAME,
+                    expected.join(", ")
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    // settings of `rule_id` for `file_path`, later matching overrides win
+    pub fn resolve(&self, rule_id: &str, file_path: &str) -> ResolvedRule {
+        let mut rule_config = self.rules.get(rule_id).cloned().unwrap_or_default();
+        if !self.overrides.is_empty() {
+            let relative_path = self.relative_path(file_path);
+            for item in &self.overrides {
+                if let Some(other) = item.rules.get(rule_id) {
+                    if item.matches(&relative_path) {
+                        rule_config.merge(other);
+                    }
+                }
+            }
+        }
+
+        ResolvedRule {
+            enabled: rule_config.enabled.unwrap_or(true),
+            severity: rule_config.severity,
+            line_scoped: rule_config.line_scoped,
+            options: RuleOptions {
+                rule_id: rule_id.to_string(),
+                root: self.root.clone(),
+                table: rule_config.options,
+            },
+        }
+    }
+
+    // contents written by `lint_master init`
+    pub fn default_toml() -> String {
+        format!(
+            r#"# lint_master configuration, see `lint_master explain <rule>` for the options
+
+[rules.eslint]
+enabled = true
+
+[rules.golangci-lint]
+enabled = true
+
+[rules.svg-attribute]
+enabled = true
+
+[rules.todo]
+enabled = true
+markers = ["{}"]
+ignore_marker = "{}"
+
+[rules.console-log]
+enabled = true
+marker = "{}"
+
+[rules.image-alt]
+enabled = true
+
+[rules.a-rel]
+enabled = true
+
+[rules.file-lines]
+enabled = true
+max_lines = {}
+
+[rules.tsx-color]
+enabled = true
+# theme_file = "{}"
+
+[rules.unused-suppression]
+enabled = true
+
+# [[overrides]]
+# files = ["apps/identity-hub/**"]
+#
+# [overrides.rules.tsx-color]
+# theme_file = "apps/identity-hub/config/theme.ts"
+"#,
+            TODO_SEARCH,
+            TODO_IGNORE_SEARCH,
+            NECESSARY_CONSOLE_LOGGING,
+            FILE_LINE,
+            RE_TSX_THEME_FILE
+        )
+    }
+
+    pub fn relative_path(&self, file_path: &str) -> PathBuf {
+        let path = fs::canonicalize(file_path).unwrap_or_else(|_| PathBuf::from(file_path));
+        path.strip_prefix(&self.root)
+            .map(Path::to_path_buf)
+            .unwrap_or(path)
+    }
+}
+
+pub struct RuleOptions {
+    rule_id: String,
+    root: PathBuf,
+    table: toml::Table,
+}
+
+impl RuleOptions {
+    fn invalid(&self, key: &str, expected: &str) -> LintError {
+        LintError::Config(format!(
+            "rule `{}`: option `{}` must be {}",
+            self.rule_id, key, expected
+        ))
+    }
+
+    pub fn usize(&self, key: &str, default: usize) -> Result<usize, LintError> {
+        match self.table.get(key) {
+            None => Ok(default),
+            Some(value) => value
+                .as_integer()
+                .and_then(|n| usize::try_from(n).ok())
+                .ok_or_else(|| self.invalid(key, "a positive integer")),
+        }
+    }
+
+    pub fn string(&self, key: &str, default: &str) -> Result<String, LintError> {
+        match self.table.get(key) {
+            None => Ok(default.to_string()),
+            Some(value) => value
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| self.invalid(key, "a string")),
+        }
+    }
+
+    pub fn strings(&self, key: &str, default: &[&str]) -> Result<Vec<String>, LintError> {
+        match self.table.get(key) {
+            None => Ok(default.iter().map(|s| s.to_string()).collect()),
+            Some(value) => value
+                .as_array()
+                .and_then(|items| {
+                    items
+                        .iter()
+                        .map(|item| item.as_str().map(str::to_string))
+                        .collect()
+                })
+                .ok_or_else(|| self.invalid(key, "an array of strings")),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn table(&self) -> &toml::Table {
+        &self.table
+    }
+
+    // a path option, relative values resolve against the config file directory
+    pub fn path(&self, key: &str, default: &str) -> Result<PathBuf, LintError> {
+        Ok(self.root.join(self.string(key, default)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(contents: &str) -> Result<(), LintError> {
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        config.validate(&Registry::builtin())
+    }
+
+    #[test]
+    fn validate_accepts_the_options_rules_read() {
+        assert!(validate("[rules.todo]\nseverity = \"error\"\nmarkers = [\"FIXME\"]\n").is_ok());
+        assert!(validate(&ProjectConfig::default_toml()).is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_misspelled_keys() {
+        let error = validate("[rules.todo]\nseverty = \"error\"\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unknown option `severty` of rule `todo` in lint-master.toml, expected one of: \
+             enabled, severity, line_scoped, markers, ignore_marker"
+        );
+        let overrides =
+            "[[overrides]]\nfiles = [\"a/**\"]\n[overrides.rules.file-lines]\nmax_line = 10\n";
+        assert!(validate(overrides).is_err());
+    }
+
+    #[test]
+    fn validate_rejects_unknown_rules() {
+        assert!(validate("[rules.nope]\nenabled = false\n").is_err());
+    }
+
+    // a config with compiled overrides, like `load` gives
+    fn config(contents: &str) -> ProjectConfig {
+        let mut config: ProjectConfig = toml::from_str(contents).unwrap();
+        for item in config.overrides.iter_mut() {
+            item.compile().unwrap();
+        }
+        config
+    }
+
+    #[test]
+    fn resolve_applies_matching_overrides_in_order() {
+        let config = config(
+            r#"
+[rules.file-lines]
+severity = "warning"
+max_lines = 100
+
+[[overrides]]
+files = ["apps/**"]
+[overrides.rules.file-lines]
+max_lines = 200
+
+[[overrides]]
+files = ["apps/legacy/*.ts"]
+[overrides.rules.file-lines]
+enabled = false
+max_lines = 300
+"#,
+        );
+
+        let rule = config.resolve("file-lines", "apps/web/a.ts");
+        assert!(rule.enabled);
+        assert_eq!(rule.severity, Some(Severity::Warning));
+        assert_eq!(rule.options.usize("max_lines", 0).unwrap(), 200);
+
+        let rule = config.resolve("file-lines", "apps/legacy/a.ts");
+        assert!(!rule.enabled);
+        assert_eq!(rule.options.usize("max_lines", 0).unwrap(), 300);
+
+        // `*` stays within a directory
+        let rule = config.resolve("file-lines", "apps/legacy/old/a.ts");
+        assert!(rule.enabled);
+        assert_eq!(rule.options.usize("max_lines", 0).unwrap(), 200);
+
+        let rule = config.resolve("file-lines", "lib/a.ts");
+        assert_eq!(rule.options.usize("max_lines", 0).unwrap(), 100);
+    }
+
+    #[test]
+    fn resolve_defaults_unconfigured_rules() {
+        let rule = ProjectConfig::default().resolve("todo", "a.ts");
+        assert!(rule.enabled);
+        assert_eq!(rule.severity, None);
+        assert_eq!(rule.line_scoped, None);
+        assert_eq!(
+            rule.options.strings("markers", &["TODO"]).unwrap(),
+            ["TODO"]
+        );
+    }
+}
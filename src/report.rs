@@ -0,0 +1,86 @@
+use crate::baseline::BaselineEntry;
+use crate::diagnostic::{Diagnostic, Severity};
+
+#[derive(Clone, Debug)]
+pub struct RuleCheck {
+    pub rule_id: String,
+    pub label: String,
+    pub diagnostics: Vec<Diagnostic>,
+    // fingerprints of every problem the rule found, including the ones suppression
+    // comments or `--changed-lines` left out of `diagnostics`
+    pub found: Vec<String>,
+}
+
+impl RuleCheck {
+    pub fn count(&self, severity: Severity) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == severity)
+            .count()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FileReport {
+    pub path: String,
+    pub checks: Vec<RuleCheck>,
+}
+
+impl FileReport {
+    pub fn diagnostics(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.checks
+            .iter()
+            .flat_map(|check| check.diagnostics.iter())
+    }
+
+    pub fn count(&self, severity: Severity) -> usize {
+        self.checks.iter().map(|check| check.count(severity)).sum()
+    }
+}
+
+// changes `--fix` made to one file, or would make with `--dry-run`
+#[derive(Clone, Debug)]
+pub struct FileFix {
+    pub path: String,
+    // number of problems fixed
+    pub fixed: usize,
+    // false with `--dry-run`
+    pub written: bool,
+    // unified diff of the change
+    pub diff: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    pub files: Vec<FileReport>,
+    // problems left out because the baseline records them
+    pub baselined: usize,
+    // baseline entries of the checked files and rules that are not found anymore
+    pub fixed: Vec<BaselineEntry>,
+    pub fixes: Vec<FileFix>,
+}
+
+impl Report {
+    pub fn diagnostics(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.files.iter().flat_map(|file| file.diagnostics())
+    }
+
+    pub fn count(&self, severity: Severity) -> usize {
+        self.diagnostics()
+            .filter(|diagnostic| diagnostic.severity == severity)
+            .count()
+    }
+
+    pub fn errors(&self) -> usize {
+        self.count(Severity::Error)
+    }
+
+    pub fn warnings(&self) -> usize {
+        self.count(Severity::Warning)
+    }
+
+    // errors always fail, warnings only once there are more than `max_warnings`
+    pub fn is_failure(&self, max_warnings: Option<usize>) -> bool {
+        self.errors() > 0 || max_warnings.is_some_and(|max| self.warnings() > max)
+    }
+}
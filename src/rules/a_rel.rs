@@ -0,0 +1,206 @@
+use super::{Rule, SourceFile};
+use crate::constants::REL_NOOPENER;
+use crate::diagnostic::{Diagnostic, Edit};
+use crate::error::LintError;
+use crate::project_config::RuleOptions;
+use crate::utils::{closing_bracket, string_end};
+use regex::Regex;
+
+pub struct ARel {}
+
+impl Rule for ARel {
+    fn id(&self) -> &str {
+        "a-rel"
+    }
+
+    fn label(&self) -> String {
+        "🍍 A_REL".to_string()
+    }
+
+    fn description(&self) -> &str {
+        "Links must set a rel attribute"
+    }
+
+    fn help(&self) -> &str {
+        r#"Reports elements with an `href=` but no `rel=` attribute, external links should
+use `rel="noopener noreferrer"`.
+
+`--fix` adds it to `<a>` tags opening in a new tab (`target="_blank"`) or linking
+to an absolute URL. It goes on its own line when the attributes are, and before
+the first spread (`{...props}`) so the spread props can still replace it."#
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["js", "ts", "tsx"]
+    }
+
+    fn check(
+        &self,
+        file: &SourceFile,
+        _options: &RuleOptions,
+    ) -> Result<Vec<Diagnostic>, LintError> {
+        let mut result = Vec::new();
+        let re = Regex::new(r#"href=[^>]*>"#).unwrap();
+        for cap in re.find_iter(&file.contents) {
+            let line = cap.as_str();
+            if !line.contains("rel=") {
+                let r = format!("a tag need set <rel> value: {}", line);
+                let mut diagnostic = Diagnostic::new(self.id(), &file.path, r)
+                    .at(file.position(cap.start()), file.position(cap.end()))
+                    .with_suggestion(format!("add {}", REL_NOOPENER));
+                if let Some(edit) = Self::insert_rel(&file.contents, cap.start()) {
+                    diagnostic = diagnostic.with_fix(format!("add {}", REL_NOOPENER), vec![edit]);
+                }
+                result.push(diagnostic);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl ARel {
+    // the edit adding the rel attribute to the `<a>` tag holding the `href=` at `href`,
+    // None for internal links, other elements and tags that could not be parsed
+    fn insert_rel(contents: &str, href: usize) -> Option<Edit> {
+        let bytes = contents.as_bytes();
+        let open = contents[..href].rfind('<')?;
+        let name = contents[open + 1..]
+            .split(|c: char| !c.is_alphanumeric() && c != '.' && c != '_')
+            .next()?;
+        if name != "a" {
+            return None;
+        }
+        let (end, spread) = Self::scan_tag(bytes, open)?;
+        if href > end || contents[open..end].contains("rel=") {
+            return None;
+        }
+
+        let value_start = href + "href=".len();
+        let value_end = match bytes.get(value_start)? {
+            b'"' | b'\'' => string_end(bytes, value_start)?,
+            b'{' => closing_bracket(bytes, value_start)?,
+            _ => return None,
+        } + 1;
+        let url = contents[value_start..value_end].trim_matches(['{', '}', '"', '\'', '`', ' ']);
+        let external = ["http://", "https://", "//"]
+            .iter()
+            .any(|scheme| url.starts_with(scheme));
+        let blank = Regex::new(r#"target=\{?\s*["'`]_blank["'`]"#)
+            .unwrap()
+            .is_match(&contents[open..end]);
+        if !external && !blank {
+            return None;
+        }
+
+        // attributes written one per line get the new one on its own line too
+        let (at, multiline) = match spread {
+            Some(spread) => (spread, Self::indent(contents, spread).is_some()),
+            None => (
+                value_end,
+                contents[value_end..end]
+                    .split(|c: char| !c.is_whitespace())
+                    .next()
+                    .is_some_and(|space| space.contains('\n')),
+            ),
+        };
+        let indent = Self::indent(contents, if spread.is_some() { at } else { href });
+        let replacement = match (spread, multiline, indent) {
+            (Some(_), true, Some(indent)) => format!("{}\n{}", REL_NOOPENER, indent),
+            (Some(_), _, _) => format!("{} ", REL_NOOPENER),
+            (None, true, Some(indent)) => format!("\n{}{}", indent, REL_NOOPENER),
+            (None, _, _) => format!(" {}", REL_NOOPENER),
+        };
+        Some(Edit::new(at, at, replacement))
+    }
+
+    // index of the `>` ending the tag opened at `open` and of its first spread attribute
+    fn scan_tag(bytes: &[u8], open: usize) -> Option<(usize, Option<usize>)> {
+        let mut spread = None;
+        let mut previous = b'<';
+        let mut i = open + 1;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'"' | b'\'' => i = string_end(bytes, i)?,
+                b'{' => {
+                    // braces right after `=` hold a value, bare ones a spread
+                    if previous != b'=' && spread.is_none() {
+                        spread = Some(i);
+                    }
+                    i = closing_bracket(bytes, i)?;
+                }
+                b'>' => return Some((i, spread)),
+                _ => {}
+            }
+            if !bytes[i].is_ascii_whitespace() {
+                previous = bytes[i];
+            }
+            i += 1;
+        }
+        None
+    }
+
+    // whitespace before `offset` when nothing else precedes it on its line
+    fn indent(contents: &str, offset: usize) -> Option<&str> {
+        let line_start = contents[..offset].rfind('\n')? + 1;
+        let indent = &contents[line_start..offset];
+        indent.trim().is_empty().then_some(indent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // contents with the fix of the first `href=` applied, None without a fix
+    fn fix(contents: &str) -> Option<String> {
+        let edit = ARel::insert_rel(contents, contents.find("href=")?)?;
+        let mut result = contents.to_string();
+        result.replace_range(edit.start..edit.end, &edit.replacement);
+        Some(result)
+    }
+
+    #[test]
+    fn inserts_after_the_href_value() {
+        assert_eq!(
+            fix(r#"<a href="https://example.com">x</a>"#).as_deref(),
+            Some(r#"<a href="https://example.com" rel="noopener noreferrer">x</a>"#)
+        );
+        assert_eq!(
+            fix(r#"<a href={"/docs"} target="_blank">x</a>"#).as_deref(),
+            Some(r#"<a href={"/docs"} rel="noopener noreferrer" target="_blank">x</a>"#)
+        );
+    }
+
+    #[test]
+    fn keeps_one_attribute_per_line() {
+        let contents = "<a\n  href=\"https://example.com\"\n  className=\"link\"\n>";
+        assert_eq!(
+            fix(contents).as_deref(),
+            Some(
+                "<a\n  href=\"https://example.com\"\n  rel=\"noopener noreferrer\"\n  className=\"link\"\n>"
+            )
+        );
+    }
+
+    #[test]
+    fn inserts_before_the_first_spread() {
+        // rel goes before the spread so a rel passed in the props still replaces it
+        assert_eq!(
+            fix(r#"<a {...props} href="https://example.com">x</a>"#).as_deref(),
+            Some(r#"<a rel="noopener noreferrer" {...props} href="https://example.com">x</a>"#)
+        );
+        let contents = "<a\n  {...props}\n  href=\"https://example.com\"\n>";
+        assert_eq!(
+            fix(contents).as_deref(),
+            Some("<a\n  rel=\"noopener noreferrer\"\n  {...props}\n  href=\"https://example.com\"\n>")
+        );
+    }
+
+    #[test]
+    fn skips_internal_links_and_other_elements() {
+        assert_eq!(fix(r#"<a href="/docs">x</a>"#), None);
+        assert_eq!(fix(r#"<Link href="https://example.com">x</Link>"#), None);
+        assert_eq!(fix(r#"<a href={url} rel={rel}>x</a>"#), None);
+    }
+}
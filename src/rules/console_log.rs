@@ -0,0 +1,219 @@
+use super::{Rule, SourceFile};
+use crate::constants::{CONSOLE_LOG, NECESSARY_CONSOLE_LOGGING};
+use crate::diagnostic::{Diagnostic, Edit};
+use crate::error::LintError;
+use crate::project_config::RuleOptions;
+use crate::utils::closing_bracket;
+
+pub struct ConsoleLog {}
+
+impl Rule for ConsoleLog {
+    fn id(&self) -> &str {
+        "console-log"
+    }
+
+    fn label(&self) -> String {
+        "🍉 CONSOLE_LOG".to_string()
+    }
+
+    fn description(&self) -> &str {
+        "console.log calls must be removed or annotated"
+    }
+
+    fn help(&self) -> &str {
+        r#"Reports `console.log(` calls. A call is accepted when the previous line is a
+`// Necessary console logging` comment or the call line carries the marker.
+`--fix` deletes the calls written as a statement of their own, including calls
+spanning several lines, unless they may be the body of an `if` or loop without
+braces.
+
+Options:
+  marker  annotation accepting the call, default "Necessary console logging""#
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["js", "ts", "tsx"]
+    }
+
+    fn options(&self) -> &[&str] {
+        &["marker"]
+    }
+
+    fn check(
+        &self,
+        file: &SourceFile,
+        options: &RuleOptions,
+    ) -> Result<Vec<Diagnostic>, LintError> {
+        let marker = options.string("marker", NECESSARY_CONSOLE_LOGGING)?;
+        let mut result = Vec::new();
+        let mut offset = 0;
+        let mut necessary = false;
+        for line in file.contents.split_inclusive('\n') {
+            let trimmed = line.trim_start();
+            if let Some(column) = line.find(CONSOLE_LOG) {
+                // a `// Necessary console logging` comment excuses the call on the next line
+                if !necessary && !line.contains(marker.as_str()) {
+                    let start = file.position(offset + column);
+                    let end = file.position(offset + column + CONSOLE_LOG.len());
+                    let r = "console.log need remove".to_string();
+                    let mut diagnostic = Diagnostic::new(self.id(), &file.path, r)
+                        .at(start, end)
+                        .with_suggestion(format!("keep it with a `// {}` comment", marker));
+                    if let Some(edit) = Self::removal(&file.contents, offset + column) {
+                        diagnostic =
+                            diagnostic.with_fix("remove console.log".to_string(), vec![edit]);
+                    }
+                    result.push(diagnostic);
+                }
+            }
+            if !trimmed.is_empty() {
+                necessary = trimmed.starts_with("//") && trimmed.contains(marker.as_str());
+            }
+            offset += line.len();
+        }
+
+        Ok(result)
+    }
+}
+
+impl ConsoleLog {
+    // the edit deleting the `console.log(...)` statement at `start`, None when the call is
+    // part of an expression or the only statement of an `if` or `else` without braces
+    fn removal(contents: &str, start: usize) -> Option<Edit> {
+        let line_start = contents[..start].rfind('\n').map_or(0, |i| i + 1);
+        if !contents[line_start..start].trim().is_empty()
+            || !Self::follows_statement(&contents[..line_start])
+        {
+            return None;
+        }
+
+        let close = closing_bracket(contents.as_bytes(), start + CONSOLE_LOG.len() - 1)?;
+        let line_end = contents[close..]
+            .find('\n')
+            .map_or(contents.len(), |i| close + i + 1);
+        let after = contents[close + 1..line_end].trim_start();
+        let after_semicolon = after.strip_prefix(';');
+        let tail = after_semicolon.unwrap_or(after).trim_start();
+
+        if tail.trim_end().is_empty() || tail.starts_with("//") {
+            // the whole line, with a trailing comment about the call
+            Some(Edit::new(line_start, line_end, String::new()))
+        } else if after_semicolon.is_some() {
+            // another statement follows on the same line
+            Some(Edit::new(start, line_end - tail.len(), String::new()))
+        } else {
+            None
+        }
+    }
+
+    // the previous code line ends a statement, so deleting the next one keeps the meaning;
+    // comments in between are skipped, a line ending in `)` may be the header of an `if`
+    fn follows_statement(before: &str) -> bool {
+        let previous = match before
+            .lines()
+            .rev()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !Self::is_comment(line))
+        {
+            Some(line) => line,
+            None => return true,
+        };
+
+        let control = ["if", "for", "while", "else", "do"].iter().any(|keyword| {
+            previous
+                .trim_start_matches(['}', ' '])
+                .strip_prefix(keyword)
+                .is_some_and(|rest| !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_'))
+        });
+        let open = [
+            "=>", "=", "(", ")", ",", "&&", "||", "?", ":", "+", "else", "do",
+        ]
+        .iter()
+        .any(|suffix| previous.ends_with(suffix));
+        !open && (!control || previous.ends_with(['{', ';']))
+    }
+
+    // a whole line comment, or a line of a block comment
+    fn is_comment(line: &str) -> bool {
+        line.starts_with("//")
+            || line.starts_with("/*")
+            || line.starts_with('*')
+            || line.ends_with("*/")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // contents with the first console.log removed, None without a fix
+    fn remove(contents: &str) -> Option<String> {
+        let edit = ConsoleLog::removal(contents, contents.find(CONSOLE_LOG)?)?;
+        let mut result = contents.to_string();
+        result.replace_range(edit.start..edit.end, &edit.replacement);
+        Some(result)
+    }
+
+    #[test]
+    fn removes_statement_lines() {
+        assert_eq!(
+            remove("const a = 1;\nconsole.log(a);\nreturn a;\n").as_deref(),
+            Some("const a = 1;\nreturn a;\n")
+        );
+        assert_eq!(
+            remove("function f() {\n  console.log(\n    a,\n    (b),\n  ) // trace\n}\n")
+                .as_deref(),
+            Some("function f() {\n}\n")
+        );
+        assert_eq!(
+            remove("console.log(a); const b = 1;\n").as_deref(),
+            Some("const b = 1;\n")
+        );
+    }
+
+    #[test]
+    fn removes_inside_braced_blocks() {
+        assert_eq!(
+            remove("if (a) {\n  console.log(a);\n}\n").as_deref(),
+            Some("if (a) {\n}\n")
+        );
+        assert_eq!(
+            remove("} else {\n  // debug\n  console.log(a);\n}\n").as_deref(),
+            Some("} else {\n  // debug\n}\n")
+        );
+    }
+
+    #[test]
+    fn skips_the_only_statement_of_a_block_without_braces() {
+        assert_eq!(remove("if (x)\n  console.log(x);\nreturn x;\n"), None);
+        assert_eq!(remove("else\n  console.log(x);\n"), None);
+        assert_eq!(remove("for (const x of xs)\n  console.log(x);\n"), None);
+    }
+
+    #[test]
+    fn skips_comments_before_a_control_header() {
+        assert_eq!(
+            remove("if (x)\n  // debug\n  console.log(x);\nreturn x;\n"),
+            None
+        );
+        assert_eq!(
+            remove("if (x)\n  /* debug\n   * output\n   */\n  console.log(x);\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn skips_multi_line_control_headers() {
+        assert_eq!(
+            remove("if (a &&\n    b)\n  console.log(a);\nreturn a;\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn skips_calls_inside_expressions() {
+        assert_eq!(remove("const a = console.log(x);\n"), None);
+        assert_eq!(remove("const f = () =>\n  console.log(x);\n"), None);
+        assert_eq!(remove("console.log(a) || b;\n"), None);
+    }
+}
@@ -0,0 +1,101 @@
+use super::{Rule, SourceFile};
+use crate::constants::RE_LINT_TS;
+use crate::diagnostic::{Diagnostic, Position, Severity};
+use crate::error::LintError;
+use crate::project_config::RuleOptions;
+use regex::Regex;
+use std::io::{self, Write};
+use std::process::{Command, Output, Stdio};
+
+pub struct EsLint {}
+
+impl Rule for EsLint {
+    fn id(&self) -> &str {
+        "eslint"
+    }
+
+    fn label(&self) -> String {
+        "🍓 ESLINT".to_string()
+    }
+
+    fn description(&self) -> &str {
+        "Runs ESLint on the file and reports its errors"
+    }
+
+    fn help(&self) -> &str {
+        r#"Spawns the `eslint` binary found on PATH for every JavaScript and TypeScript
+file and reports the problems it prints. ESLint itself is configured by the
+project's own ESLint config."#
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["js", "ts", "tsx"]
+    }
+
+    // ESLint reads nested configs, plugins and tsconfig files lint_master cannot
+    // list, so its results are never cached
+    fn cacheable(&self) -> bool {
+        false
+    }
+
+    fn check(
+        &self,
+        file: &SourceFile,
+        _options: &RuleOptions,
+    ) -> Result<Vec<Diagnostic>, LintError> {
+        let output = if file.staged {
+            // staged contents go through stdin, eslint still resolves its config from the path
+            Self::run_stdin(file)
+        } else {
+            Command::new("eslint").arg(&file.path).output()
+        }
+        .map_err(|e| LintError::command("eslint", e))?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        let re = Regex::new(RE_LINT_TS).unwrap();
+        let result = stdout
+            .lines()
+            .filter_map(|line| re.captures(line))
+            .map(|cap| {
+                let position = Position::new(cap[1].parse().unwrap(), cap[2].parse().unwrap());
+                let mut message = cap[4].trim().to_string();
+                if let Some(name) = cap.get(5) {
+                    message = format!("{} ({})", message, name.as_str());
+                }
+                let severity = if &cap[3] == "warning" {
+                    Severity::Warning
+                } else {
+                    Severity::Error
+                };
+                let mut diagnostic = Diagnostic::new(self.id(), &file.path, message)
+                    .at(position, position)
+                    .with_severity(severity);
+                if let Some(name) = cap.get(5) {
+                    diagnostic = diagnostic.with_code(name.as_str());
+                }
+                diagnostic
+            })
+            .collect();
+
+        Ok(result)
+    }
+}
+
+impl EsLint {
+    fn run_stdin(file: &SourceFile) -> io::Result<Output> {
+        let mut child = Command::new("eslint")
+            .args(["--stdin", "--stdin-filename", &file.path])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            // eslint may exit before reading everything, its output still tells why
+            match stdin.write_all(file.contents.as_bytes()) {
+                Err(e) if e.kind() != io::ErrorKind::BrokenPipe => return Err(e),
+                _ => {}
+            }
+        }
+        child.wait_with_output()
+    }
+}
@@ -0,0 +1,60 @@
+use super::{Rule, SourceFile};
+use crate::constants::FILE_LINE;
+use crate::diagnostic::{Diagnostic, Position};
+use crate::error::LintError;
+use crate::project_config::RuleOptions;
+
+pub struct FileLines {}
+
+impl Rule for FileLines {
+    fn id(&self) -> &str {
+        "file-lines"
+    }
+
+    fn label(&self) -> String {
+        "🍌 FILE_LINES".to_string()
+    }
+
+    fn description(&self) -> &str {
+        "Newly added files must stay under the line limit"
+    }
+
+    fn help(&self) -> &str {
+        r#"Reports files added to the git index that are longer than the limit.
+
+Options:
+  max_lines  maximum number of lines, default 150"#
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["js", "ts", "tsx", "go"]
+    }
+
+    fn options(&self) -> &[&str] {
+        &["max_lines"]
+    }
+
+    fn line_scoped(&self) -> bool {
+        false
+    }
+
+    fn check(
+        &self,
+        file: &SourceFile,
+        options: &RuleOptions,
+    ) -> Result<Vec<Diagnostic>, LintError> {
+        let mut result = Vec::new();
+        let len = file.contents.lines().count();
+        let max = options.usize("max_lines", FILE_LINE)?;
+
+        if file.is_added && len > max {
+            let r = format!("File cannot be larger than {} lines, has {}", max, len);
+            result.push(
+                Diagnostic::new(self.id(), &file.path, r)
+                    .at(Position::new(max + 1, 1), Position::new(len, 1)),
+            );
+        }
+
+        Ok(result)
+    }
+}
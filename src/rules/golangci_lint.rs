@@ -0,0 +1,115 @@
+use super::{Rule, SourceFile};
+use crate::constants::RE_LINT_GO;
+use crate::diagnostic::{Diagnostic, Position, Severity};
+use crate::error::LintError;
+use crate::project_config::RuleOptions;
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+pub struct GolangciLint {}
+
+impl Rule for GolangciLint {
+    fn id(&self) -> &str {
+        "golangci-lint"
+    }
+
+    fn label(&self) -> String {
+        "🍓 GOLANGCI_LINT".to_string()
+    }
+
+    fn description(&self) -> &str {
+        "Runs golangci-lint on the file and reports its issues"
+    }
+
+    fn help(&self) -> &str {
+        r#"Spawns `golangci-lint run <file>` for every Go file and reports the issues it
+prints. golangci-lint itself is configured by the project's `.golangci.yml`.
+
+Its text output carries no severity, so issues are warnings; `severity = "error"`
+in lint-master.toml makes them fail the run."#
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["go"]
+    }
+
+    // golangci-lint reads nested configs, plugins and the Go modules lint_master
+    // cannot list, so its results are never cached
+    fn cacheable(&self) -> bool {
+        false
+    }
+
+    fn check(
+        &self,
+        file: &SourceFile,
+        _options: &RuleOptions,
+    ) -> Result<Vec<Diagnostic>, LintError> {
+        // golangci-lint only reads files, staged contents are written next to the
+        // original so the package and module still resolve
+        let staged_copy = if file.staged {
+            Some(StagedCopy::write(file)?)
+        } else {
+            None
+        };
+        let target = staged_copy
+            .as_ref()
+            .map(|copy| copy.path.to_string_lossy().into_owned())
+            .unwrap_or_else(|| file.path.clone());
+
+        let output = Command::new("golangci-lint")
+            .args(["run", &target])
+            .output()
+            .map_err(|e| LintError::command("golangci-lint", e))?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        let re = Regex::new(RE_LINT_GO).unwrap();
+        // messages end with the reporting linter, e.g. `(gofmt)`
+        let re_linter = Regex::new(r"\(([\w-]+)\)$").unwrap();
+        let result = re
+            .captures_iter(&stdout)
+            .map(|cap| {
+                let position = Position::new(cap[1].parse().unwrap(), cap[2].parse().unwrap());
+                let message = cap[3].trim();
+                let mut diagnostic = Diagnostic::new(self.id(), &file.path, message.to_string())
+                    .at(position, position);
+                if let Some(linter) = re_linter.captures(message) {
+                    diagnostic = diagnostic.with_code(&linter[1]);
+                }
+                diagnostic
+            })
+            .collect();
+
+        Ok(result)
+    }
+}
+
+// temporary copy of the staged contents, removed on drop
+struct StagedCopy {
+    path: PathBuf,
+}
+
+impl StagedCopy {
+    fn write(file: &SourceFile) -> Result<StagedCopy, LintError> {
+        let original = Path::new(&file.path);
+        let file_name = original
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let path = original.with_file_name(format!("lint_master_staged_{}", file_name));
+        fs::write(&path, &file.contents)
+            .map_err(|e| LintError::write(&path.display().to_string(), e))?;
+        Ok(StagedCopy { path })
+    }
+}
+
+impl Drop for StagedCopy {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
@@ -0,0 +1,50 @@
+use super::{Rule, SourceFile};
+use crate::diagnostic::Diagnostic;
+use crate::error::LintError;
+use crate::project_config::RuleOptions;
+use regex::Regex;
+
+pub struct ImageAlt {}
+
+impl Rule for ImageAlt {
+    fn id(&self) -> &str {
+        "image-alt"
+    }
+
+    fn label(&self) -> String {
+        "🍎 IMAGE_ALT".to_string()
+    }
+
+    fn description(&self) -> &str {
+        "<Image> elements must set an alt text"
+    }
+
+    fn help(&self) -> &str {
+        r#"Reports `<Image>` elements without an `alt=` attribute."#
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["js", "ts", "tsx"]
+    }
+
+    fn check(
+        &self,
+        file: &SourceFile,
+        _options: &RuleOptions,
+    ) -> Result<Vec<Diagnostic>, LintError> {
+        let mut result = Vec::new();
+        let re = Regex::new(r#"<Image[^>]*>"#).unwrap();
+        for cap in re.find_iter(&file.contents) {
+            let line = cap.as_str();
+            if !line.contains("alt=") {
+                let r = format!("img tag need set <alt> value: {}", line);
+                result.push(
+                    Diagnostic::new(self.id(), &file.path, r)
+                        .at(file.position(cap.start()), file.position(cap.end())),
+                );
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,145 @@
+mod a_rel;
+mod console_log;
+mod eslint;
+mod file_lines;
+mod golangci_lint;
+mod image_alt;
+mod svg_attribute;
+mod todo;
+mod tsx_color;
+mod unused_suppression;
+
+pub use a_rel::ARel;
+pub use console_log::ConsoleLog;
+pub use eslint::EsLint;
+pub use file_lines::FileLines;
+pub use golangci_lint::GolangciLint;
+pub use image_alt::ImageAlt;
+pub use svg_attribute::SvgAttribute;
+pub use todo::Todo;
+pub use tsx_color::TsxColor;
+pub use unused_suppression::UnusedSuppression;
+
+use crate::diagnostic::{Diagnostic, Position, Severity};
+use crate::error::LintError;
+use crate::project_config::RuleOptions;
+use std::path::PathBuf;
+
+pub struct SourceFile {
+    pub path: String,
+    pub extension: String,
+    pub contents: String,
+    // file is newly added to the git index
+    pub is_added: bool,
+    // contents come from the git index and may differ from the file on disk
+    pub staged: bool,
+}
+
+impl SourceFile {
+    pub fn position(&self, offset: usize) -> Position {
+        Position::from_offset(&self.contents, offset)
+    }
+}
+
+// rules run on a worker pool, so they must be shareable across threads
+pub trait Rule: Send + Sync {
+    fn id(&self) -> &str;
+
+    // row title used by the result table
+    fn label(&self) -> String {
+        self.id().to_uppercase()
+    }
+
+    // one line summary shown by `lint_master rules`
+    fn description(&self) -> &str {
+        ""
+    }
+
+    // longer documentation with the supported options, shown by `lint_master explain`
+    fn help(&self) -> &str {
+        ""
+    }
+
+    // most severe level the rule reports, lint-master.toml `severity` replaces it
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    // lowercase file extensions the rule applies to, e.g. ["ts", "tsx"]
+    fn extensions(&self) -> &[&str];
+
+    // option keys the rule reads, lint-master.toml rejects any other
+    fn options(&self) -> &[&str] {
+        &[]
+    }
+
+    // diagnostics point at the offending lines, --changed-lines drops the ones outside
+    // the diff; rules judging the file as a whole return false to always report
+    fn line_scoped(&self) -> bool {
+        true
+    }
+
+    // files besides the checked one the result depends on, cached results are
+    // dropped when one of them changes
+    fn dependencies(&self, _options: &RuleOptions) -> Result<Vec<PathBuf>, LintError> {
+        Ok(Vec::new())
+    }
+
+    // false for rules whose result depends on more than the file, its options and
+    // dependencies, they run every time
+    fn cacheable(&self) -> bool {
+        true
+    }
+
+    fn check(&self, file: &SourceFile, options: &RuleOptions)
+        -> Result<Vec<Diagnostic>, LintError>;
+}
+
+pub struct Registry {
+    rules: Vec<Box<dyn Rule>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry { rules: Vec::new() }
+    }
+
+    pub fn builtin() -> Registry {
+        let mut registry = Registry::new();
+        registry.register(EsLint {});
+        registry.register(GolangciLint {});
+        registry.register(SvgAttribute {});
+        registry.register(Todo {});
+        registry.register(ConsoleLog {});
+        registry.register(ImageAlt {});
+        registry.register(ARel {});
+        registry.register(FileLines {});
+        registry.register(TsxColor {});
+        registry.register(UnusedSuppression {});
+        registry
+    }
+
+    pub fn register<R: Rule + 'static>(&mut self, rule: R) {
+        self.rules.push(Box::new(rule));
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = &dyn Rule> {
+        self.rules.iter().map(|rule| rule.as_ref())
+    }
+
+    pub fn get(&self, id: &str) -> Option<&dyn Rule> {
+        self.rules().find(|rule| rule.id() == id)
+    }
+
+    pub fn rules_for(&self, extension: &str) -> Vec<&dyn Rule> {
+        self.rules()
+            .filter(|rule| rule.extensions().contains(&extension))
+            .collect()
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Registry {
+        Registry::builtin()
+    }
+}
@@ -0,0 +1,146 @@
+use super::{Rule, SourceFile};
+use crate::constants::SVG_ATTRIBUTE_NAMES;
+use crate::diagnostic::{Diagnostic, Edit};
+use crate::error::LintError;
+use crate::project_config::RuleOptions;
+use crate::utils::{convert_to_camel_case, is_in_jsx_tag};
+use std::collections::BTreeSet;
+
+pub struct SvgAttribute {}
+
+impl Rule for SvgAttribute {
+    fn id(&self) -> &str {
+        "svg-attribute"
+    }
+
+    fn label(&self) -> String {
+        "🥝 SVG".to_string()
+    }
+
+    fn description(&self) -> &str {
+        "SVG attributes in TSX must use their React camelCase name"
+    }
+
+    fn help(&self) -> &str {
+        r#"React expects camelCase SVG attributes, `stroke-width` must be written as
+`strokeWidth`. `--fix` renames the ones written as JSX attributes.
+
+Options:
+  attributes  kebab-case attribute names to look for"#
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tsx"]
+    }
+
+    fn options(&self) -> &[&str] {
+        &["attributes"]
+    }
+
+    fn check(
+        &self,
+        file: &SourceFile,
+        options: &RuleOptions,
+    ) -> Result<Vec<Diagnostic>, LintError> {
+        // a name listed twice would report every use twice
+        let attributes: BTreeSet<String> = options
+            .strings("attributes", &SVG_ATTRIBUTE_NAMES)?
+            .into_iter()
+            .collect();
+        let mut result = Vec::new();
+        for attribute_name in &attributes {
+            let camel_case = convert_to_camel_case(attribute_name);
+            for (offset, _) in file.contents.match_indices(attribute_name.as_str()) {
+                let end = offset + attribute_name.len();
+                if !Self::is_whole_name(&file.contents, offset, end) {
+                    continue;
+                }
+                let r = format!("need replace {} to {}", attribute_name, camel_case);
+                let mut diagnostic = Diagnostic::new(self.id(), &file.path, r)
+                    .at(file.position(offset), file.position(end))
+                    .with_suggestion(camel_case.clone());
+                if Self::is_jsx_attribute(&file.contents, offset, end) {
+                    diagnostic = diagnostic.with_fix(
+                        format!("rename {} to {}", attribute_name, camel_case),
+                        vec![Edit::new(offset, end, camel_case.clone())],
+                    );
+                }
+                result.push(diagnostic);
+            }
+        }
+        result.sort_by_key(|d| d.start);
+
+        Ok(result)
+    }
+}
+
+impl SvgAttribute {
+    // `color-interpolation` is not reported inside `color-interpolation-filters`
+    fn is_whole_name(contents: &str, start: usize, end: usize) -> bool {
+        let is_name_char = |c: char| c.is_alphanumeric() || c == '_' || c == '-';
+        !contents[..start].ends_with(is_name_char) && !contents[end..].starts_with(is_name_char)
+    }
+
+    // `start..end` is a whole attribute name inside a JSX tag, e.g. `<path stroke-width={2}`;
+    // text, strings and style keys using the same words are not
+    fn is_jsx_attribute(contents: &str, start: usize, end: usize) -> bool {
+        let before = &contents[..start];
+        if !before.ends_with(char::is_whitespace) {
+            return false;
+        }
+        contents[end..].trim_start().starts_with('=') && is_in_jsx_tag(contents, start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project_config::ProjectConfig;
+
+    fn check(contents: &str) -> Vec<Diagnostic> {
+        let file = SourceFile {
+            path: "a.tsx".to_string(),
+            extension: "tsx".to_string(),
+            contents: contents.to_string(),
+            is_added: false,
+            staged: false,
+        };
+        let options = ProjectConfig::default()
+            .resolve("svg-attribute", &file.path)
+            .options;
+        SvgAttribute {}.check(&file, &options).unwrap()
+    }
+
+    #[test]
+    fn reports_each_use_once() {
+        let diagnostics = check(r#"<svg shape-rendering="auto" />"#);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "need replace shape-rendering to shapeRendering"
+        );
+    }
+
+    #[test]
+    fn skips_names_inside_longer_names() {
+        let diagnostics =
+            check(r#"<svg color-interpolation-filters="sRGB" data-stroke-width="1" />"#);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].suggestion.as_deref(),
+            Some("colorInterpolationFilters")
+        );
+    }
+
+    #[test]
+    fn fixes_jsx_attributes_only() {
+        let diagnostics = check("<path stroke-width={2} />\nconst text = 'stroke-width';\n");
+
+        assert_eq!(diagnostics.len(), 2);
+        let fix = diagnostics[0].fix.as_ref().unwrap();
+        assert_eq!(fix.edits, vec![Edit::new(6, 18, "strokeWidth".to_string())]);
+        assert!(diagnostics[1].fix.is_none());
+    }
+}
@@ -0,0 +1,69 @@
+use super::{Rule, SourceFile};
+use crate::constants::{TODO_IGNORE_SEARCH, TODO_SEARCH};
+use crate::diagnostic::{Diagnostic, Position, Severity};
+use crate::error::LintError;
+use crate::project_config::RuleOptions;
+
+pub struct Todo {}
+
+impl Rule for Todo {
+    fn id(&self) -> &str {
+        "todo"
+    }
+
+    fn label(&self) -> String {
+        "🍋 TODO".to_string()
+    }
+
+    fn description(&self) -> &str {
+        "Comments must not leave unresolved TODO markers"
+    }
+
+    fn help(&self) -> &str {
+        r#"Reports `//` comments containing a marker such as `TODO`. A comment that also
+contains the ignore marker is accepted.
+
+Options:
+  markers        markers to report, default ["TODO"]
+  ignore_marker  marker accepting the comment, default "IGNORE""#
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["js", "ts", "tsx", "go"]
+    }
+
+    fn options(&self) -> &[&str] {
+        &["markers", "ignore_marker"]
+    }
+
+    fn check(
+        &self,
+        file: &SourceFile,
+        options: &RuleOptions,
+    ) -> Result<Vec<Diagnostic>, LintError> {
+        let markers = options.strings("markers", &[TODO_SEARCH])?;
+        let ignore_marker = options.string("ignore_marker", TODO_IGNORE_SEARCH)?;
+        let mut result = Vec::new();
+        for (line_number, line) in file.contents.lines().enumerate() {
+            let indent = line.len() - line.trim_start().len();
+            let comment = line.trim();
+            if comment.starts_with("//")
+                && markers
+                    .iter()
+                    .any(|marker| comment.contains(marker.as_str()))
+                && !comment.contains(ignore_marker.as_str())
+            {
+                let start = Position::new(line_number + 1, indent + 1);
+                let end = Position::new(line_number + 1, line.chars().count() + 1);
+                let r = format!("has unresolved marker {}", comment);
+                result.push(Diagnostic::new(self.id(), &file.path, r).at(start, end));
+            }
+        }
+
+        Ok(result)
+    }
+}
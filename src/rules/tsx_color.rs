@@ -0,0 +1,521 @@
+use super::{Rule, SourceFile};
+use crate::constants::{RE_MATCH_COLOR, RE_TSX_THEME_FILE};
+use crate::diagnostic::{Diagnostic, Edit};
+use crate::error::LintError;
+use crate::project_config::RuleOptions;
+use crate::utils::is_in_jsx_tag;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+
+pub struct TsxColor {}
+
+impl Rule for TsxColor {
+    fn id(&self) -> &str {
+        "tsx-color"
+    }
+
+    fn label(&self) -> String {
+        "🎨 COLOR".to_string()
+    }
+
+    fn description(&self) -> &str {
+        "Hex colors defined by the theme must use the theme token"
+    }
+
+    fn help(&self) -> &str {
+        r##"Reports `#RRGGBB` literals that are also defined in the theme file, the theme
+constant should be used instead. Files declaring `xmlns` are skipped.
+
+`--fix` replaces string literals holding only the color with the theme token,
+e.g. `'#1A2B3C'` with `theme.colors.primary` and `color="#1A2B3C"` with
+`color={theme.colors.primary}`, and imports the theme when needed.
+
+Options:
+  theme_file    theme file path relative to lint-master.toml, the rule reports
+                nothing when it does not exist
+  theme_import  module to import the theme from, default the path relative to
+                the fixed file, e.g. "@/config/theme"
+  theme_name    name of a default exported theme object, default "theme""##
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["js", "ts", "tsx"]
+    }
+
+    fn options(&self) -> &[&str] {
+        &["theme_file", "theme_import", "theme_name"]
+    }
+
+    fn dependencies(&self, options: &RuleOptions) -> Result<Vec<PathBuf>, LintError> {
+        Ok(vec![options.path("theme_file", RE_TSX_THEME_FILE)?])
+    }
+
+    fn check(
+        &self,
+        file: &SourceFile,
+        options: &RuleOptions,
+    ) -> Result<Vec<Diagnostic>, LintError> {
+        let theme_file = options.path("theme_file", RE_TSX_THEME_FILE)?;
+        // a project without the theme file has no theme colors to report
+        let color_contents = match fs::read_to_string(&theme_file) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(LintError::io(&theme_file.display().to_string(), e)),
+        };
+        let mut result = Vec::new();
+        let re_s = Regex::new(r"xmlns").unwrap();
+
+        if re_s.is_match(&file.contents) {
+            return Ok(result);
+        }
+
+        let re = Regex::new(RE_MATCH_COLOR).unwrap();
+        // hex digits are matched case-insensitively, like the tokens
+        let theme_colors: HashSet<String> = re
+            .find_iter(&color_contents)
+            .map(|cap| cap.as_str().to_lowercase())
+            .collect();
+        let tokens = ThemeToken::parse(&color_contents, &options.string("theme_name", "theme")?);
+        // the theme file defines the colors, replacing them with themselves would break it
+        let is_theme_file = fs::canonicalize(&file.path)
+            .is_ok_and(|path| fs::canonicalize(&theme_file).is_ok_and(|theme| theme == path));
+        let theme_import = options.string("theme_import", "")?;
+        // import edit of every theme binding, None when the file imports it already
+        let mut imports: HashMap<&str, Option<Edit>> = HashMap::new();
+
+        for cap in re.find_iter(&file.contents) {
+            let color = cap.as_str();
+            if theme_colors.contains(&color.to_lowercase()) {
+                let r = format!("Color {} need replace theme.ts definition", color);
+                let mut diagnostic = Diagnostic::new(self.id(), &file.path, r)
+                    .at(file.position(cap.start()), file.position(cap.end()));
+                if let Some(token) = tokens.get(&color.to_lowercase()) {
+                    diagnostic = diagnostic.with_suggestion(token.path.clone());
+                    if let (false, Some(mut edits)) = (
+                        is_theme_file,
+                        Self::replace_literal(&file.contents, cap.start(), cap.end(), token),
+                    ) {
+                        let import = imports.entry(&token.binding).or_insert_with(|| {
+                            Self::import(file, &theme_file, &theme_import, token)
+                        });
+                        edits.extend(import.clone());
+                        diagnostic = diagnostic
+                            .with_fix(format!("replace {} with {}", color, token.path), edits);
+                    }
+                }
+                result.push(diagnostic);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl TsxColor {
+    // the edit replacing a string literal holding only the color `start..end`
+    fn replace_literal(
+        contents: &str,
+        start: usize,
+        end: usize,
+        token: &ThemeToken,
+    ) -> Option<Vec<Edit>> {
+        let quote = contents[..start].chars().next_back()?;
+        if !matches!(quote, '"' | '\'') || !contents[end..].starts_with(quote) {
+            return None;
+        }
+
+        let literal_start = start - 1;
+        let replacement = match contents[..literal_start].trim_end().strip_suffix('=') {
+            Some(before) if !before.ends_with(['=', '!', '<', '>']) => {
+                if is_in_jsx_tag(contents, literal_start) {
+                    // a JSX attribute value needs braces around the expression
+                    format!("{{{}}}", token.path)
+                } else if before.ends_with(char::is_whitespace) {
+                    token.path.clone()
+                } else {
+                    // `color="#1A2B3C"` in a tag the scan could not follow
+                    return None;
+                }
+            }
+            _ => token.path.clone(),
+        };
+        Some(vec![Edit::new(literal_start, end + 1, replacement)])
+    }
+
+    // the edit importing the theme binding, None when the file already imports it
+    fn import(
+        file: &SourceFile,
+        theme_file: &Path,
+        theme_import: &str,
+        token: &ThemeToken,
+    ) -> Option<Edit> {
+        let imported = Regex::new(&format!(
+            r"import\s[^;]*\b{}\b[^;]*from",
+            regex::escape(&token.binding)
+        ))
+        .unwrap();
+        if imported.is_match(&file.contents) {
+            return None;
+        }
+
+        let module = if theme_import.is_empty() {
+            Self::relative_module(&file.path, theme_file)
+        } else {
+            theme_import.to_string()
+        };
+        let statement = if token.default_export {
+            format!("import {} from '{}';\n", token.binding, module)
+        } else {
+            format!("import {{ {} }} from '{}';\n", token.binding, module)
+        };
+
+        // after the last import, or after leading directives like 'use client'
+        let last_import = Regex::new(r#"(?m)^import\b[^;]*?['"][^'"\n]*['"];?[ \t]*(?:\n|$)"#)
+            .unwrap()
+            .find_iter(&file.contents)
+            .last()
+            .map(|m| m.end());
+        let offset = last_import.unwrap_or_else(|| {
+            Regex::new(r#"^(?:\s*['"]use [a-z ]+['"];?[ \t]*\n)*"#)
+                .unwrap()
+                .find(&file.contents)
+                .map_or(0, |m| m.end())
+        });
+        let statement = if offset > 0 && !file.contents[..offset].ends_with('\n') {
+            format!("\n{}", statement)
+        } else {
+            statement
+        };
+        Some(Edit::new(offset, offset, statement))
+    }
+
+    // import path of `theme_file` from the directory of `file_path`, without extension
+    fn relative_module(file_path: &str, theme_file: &Path) -> String {
+        let dir = match Path::new(file_path).parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let from = fs::canonicalize(dir).unwrap_or_default();
+        let to = fs::canonicalize(theme_file)
+            .unwrap_or_else(|_| theme_file.to_path_buf())
+            .with_extension("");
+        let from: Vec<Component> = from.components().collect();
+        let to: Vec<Component> = to.components().collect();
+        let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+        let mut parts: Vec<String> = vec!["..".to_string(); from.len() - common];
+        if parts.is_empty() {
+            parts.push(".".to_string());
+        }
+        parts.extend(
+            to[common..]
+                .iter()
+                .map(|part| part.as_os_str().to_string_lossy().into_owned()),
+        );
+        parts.join("/")
+    }
+}
+
+// a color defined by the theme file, e.g. `theme.colors.primary`
+struct ThemeToken {
+    path: String,
+    binding: String,
+    default_export: bool,
+}
+
+impl ThemeToken {
+    // scans the object literals of the theme file, keyed by lowercase color; colors
+    // inside arrays or computed keys have no token
+    fn parse(contents: &str, default_name: &str) -> HashMap<String, ThemeToken> {
+        let color = Regex::new(&format!("^{}$", RE_MATCH_COLOR)).unwrap();
+        let default_binding = Regex::new(r"export\s+default\s+([A-Za-z_$][\w$]*)")
+            .unwrap()
+            .captures(contents)
+            .map(|cap| cap[1].to_string());
+        let chars: Vec<char> = contents.chars().collect();
+        let mut tokens = HashMap::new();
+        // key of every open brace, None for brackets and unnamed objects
+        let mut stack: Vec<Option<String>> = Vec::new();
+        let mut pending: Option<String> = None;
+        let mut previous_word = String::new();
+        let mut i = 0;
+
+        let next_char = |mut j: usize| {
+            while j < chars.len() && chars[j].is_whitespace() {
+                j += 1;
+            }
+            chars.get(j).copied()
+        };
+
+        while i < chars.len() {
+            let c = chars[i];
+            if c == '/' && chars.get(i + 1) == Some(&'/') {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+                i += 2;
+                while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                    i += 1;
+                }
+                i += 2;
+            } else if matches!(c, '"' | '\'' | '`') {
+                let mut value = String::new();
+                i += 1;
+                while i < chars.len() && chars[i] != c {
+                    if chars[i] == '\\' {
+                        i += 1;
+                    }
+                    if let Some(&ch) = chars.get(i) {
+                        value.push(ch);
+                    }
+                    i += 1;
+                }
+                i += 1;
+                if next_char(i) == Some(':') {
+                    pending = Some(value);
+                } else if let Some(key) = pending.take() {
+                    if color.is_match(&value) && stack.iter().all(Option::is_some) {
+                        let mut path: Vec<String> = stack.iter().flatten().cloned().collect();
+                        path.push(key);
+                        let is_default = path[0].is_empty();
+                        if is_default {
+                            path[0] = default_name.to_string();
+                        }
+                        tokens.entry(value.to_lowercase()).or_insert(ThemeToken {
+                            path: Self::join(&path),
+                            binding: path[0].clone(),
+                            default_export: is_default
+                                || default_binding.as_deref() == Some(path[0].as_str()),
+                        });
+                    }
+                }
+                continue;
+            } else if c.is_alphabetic() || c == '_' || c == '$' {
+                let start = i;
+                while i < chars.len()
+                    && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '$')
+                {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                match next_char(i) {
+                    Some(':') => pending = Some(word.clone()),
+                    Some('=') if matches!(previous_word.as_str(), "const" | "let" | "var") => {
+                        pending = Some(word.clone())
+                    }
+                    Some('{') if word == "default" && previous_word == "export" => {
+                        // `export default {`, named after the `theme_name` option
+                        pending = Some(String::new())
+                    }
+                    _ => {}
+                }
+                previous_word = word;
+                continue;
+            } else if c == '{' {
+                stack.push(pending.take());
+            } else if c == '[' {
+                pending = None;
+                stack.push(None);
+            } else if c == '}' || c == ']' {
+                stack.pop();
+            } else if c == ',' || c == ';' {
+                pending = None;
+            }
+            i += 1;
+        }
+
+        tokens
+    }
+
+    // dotted path, keys that are not identifiers use brackets
+    fn join(path: &[String]) -> String {
+        let identifier = Regex::new(r"^[A-Za-z_$][\w$]*$").unwrap();
+        let mut result = path[0].clone();
+        for key in &path[1..] {
+            if identifier.is_match(key) {
+                result.push('.');
+                result.push_str(key);
+            } else {
+                result.push_str(&format!("[{:?}]", key));
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project_config::ProjectConfig;
+
+    fn token(path: &str, default_export: bool) -> ThemeToken {
+        ThemeToken {
+            path: path.to_string(),
+            binding: path.split('.').next().unwrap().to_string(),
+            default_export,
+        }
+    }
+
+    // contents with the color literal replaced by `theme.colors.primary`
+    fn replace(contents: &str) -> Option<String> {
+        let start = contents.find('#')?;
+        let edits = TsxColor::replace_literal(
+            contents,
+            start,
+            start + 7,
+            &token("theme.colors.primary", false),
+        )?;
+        let mut result = contents.to_string();
+        result.replace_range(edits[0].start..edits[0].end, &edits[0].replacement);
+        Some(result)
+    }
+
+    fn source(contents: &str) -> SourceFile {
+        SourceFile {
+            path: "src/a.tsx".to_string(),
+            extension: "tsx".to_string(),
+            contents: contents.to_string(),
+            is_added: false,
+            staged: false,
+        }
+    }
+
+    // statement and offset of the import edit
+    fn import(contents: &str, token: &ThemeToken) -> Option<(String, usize)> {
+        let edit = TsxColor::import(&source(contents), Path::new("theme.ts"), "@/theme", token)?;
+        Some((edit.replacement, edit.start))
+    }
+
+    #[test]
+    fn replaces_string_literals() {
+        assert_eq!(
+            replace("const c = '#1A2B3C';").as_deref(),
+            Some("const c = theme.colors.primary;")
+        );
+        assert_eq!(
+            replace(r##"style={{ color: "#1A2B3C" }}"##).as_deref(),
+            Some("style={{ color: theme.colors.primary }}")
+        );
+        assert_eq!(
+            replace(r##"<Box color="#1A2B3C" />"##).as_deref(),
+            Some("<Box color={theme.colors.primary} />")
+        );
+        assert_eq!(
+            replace("if (c === '#1A2B3C') {}").as_deref(),
+            Some("if (c === theme.colors.primary) {}")
+        );
+    }
+
+    #[test]
+    fn skips_colors_inside_longer_strings() {
+        assert_eq!(replace("const b = '1px solid #1A2B3C';"), None);
+        assert_eq!(replace("const b = `#1A2B3C`;"), None);
+    }
+
+    #[test]
+    fn imports_after_the_last_import() {
+        let contents =
+            "'use client';\nimport a from 'a';\nimport { b } from \"b\";\n\nconst x = 1;\n";
+        assert_eq!(
+            import(contents, &token("theme.colors.primary", false)),
+            Some((
+                "import { theme } from '@/theme';\n".to_string(),
+                contents.find("\nconst").unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn imports_after_directives() {
+        let contents = "'use client';\nconst x = 1;\n";
+        assert_eq!(
+            import(contents, &token("palette.primary", true)),
+            Some(("import palette from '@/theme';\n".to_string(), 14))
+        );
+    }
+
+    #[test]
+    fn skips_bindings_already_imported() {
+        let contents = "import { theme, spacing } from '@/theme';\n";
+        assert_eq!(
+            import(contents, &token("theme.colors.primary", false)),
+            None
+        );
+    }
+
+    #[test]
+    fn reports_colors_in_any_case() {
+        let root =
+            std::env::temp_dir().join(format!("lint-master-tsx-color-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(
+            root.join("theme.ts"),
+            "export const theme = { primary: '#1A2B3C' };\n",
+        )
+        .unwrap();
+        let mut project: ProjectConfig = toml::from_str(
+            "[rules.tsx-color]\ntheme_file = \"theme.ts\"\ntheme_import = \"@/theme\"\n",
+        )
+        .unwrap();
+        project.root = root.clone();
+        let options = project.resolve("tsx-color", "src/a.tsx").options;
+
+        let file = source("const a = { background: '#1a2b3c' };\n");
+        let diagnostics = TsxColor {}.check(&file, &options).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].suggestion.as_deref(), Some("theme.primary"));
+        assert!(diagnostics[0].fix.is_some());
+    }
+
+    #[test]
+    fn skips_a_missing_theme_file() {
+        let project = ProjectConfig {
+            root: std::env::temp_dir().join("lint-master-tsx-color-missing"),
+            ..Default::default()
+        };
+        let options = project.resolve("tsx-color", "src/a.tsx").options;
+
+        let file = source("const a = { background: '#1a2b3c' };\n");
+        assert!(TsxColor {}.check(&file, &options).unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_theme_paths() {
+        let contents = r##"
+export const theme = {
+  colors: {
+    primary: '#1A2B3C',
+    'brand-x': "#FFFFFF",
+    // secondary: '#222222',
+  },
+  gradient: ['#000000', '#111111'],
+};
+"##;
+        let tokens = ThemeToken::parse(contents, "theme");
+
+        assert_eq!(tokens["#1a2b3c"].path, "theme.colors.primary");
+        assert_eq!(tokens["#ffffff"].path, r#"theme.colors["brand-x"]"#);
+        assert!(!tokens["#1a2b3c"].default_export);
+        assert!(!tokens.contains_key("#222222"));
+        assert!(!tokens.contains_key("#000000"));
+    }
+
+    #[test]
+    fn parse_default_exports() {
+        let tokens = ThemeToken::parse("export default { colors: { a: '#111111' } };", "theme");
+        assert_eq!(tokens["#111111"].path, "theme.colors.a");
+        assert!(tokens["#111111"].default_export);
+
+        let contents = "const palette = { primary: '#222222' };\nexport default palette;\n";
+        let tokens = ThemeToken::parse(contents, "theme");
+        assert_eq!(tokens["#222222"].path, "palette.primary");
+        assert_eq!(tokens["#222222"].binding, "palette");
+        assert!(tokens["#222222"].default_export);
+    }
+}
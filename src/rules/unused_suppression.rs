@@ -0,0 +1,44 @@
+use super::{Rule, SourceFile};
+use crate::constants::UNUSED_SUPPRESSION;
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::error::LintError;
+use crate::project_config::RuleOptions;
+
+// the problems are found by the check itself once every other rule ran on the file,
+// the rule only makes them configurable like the others
+pub struct UnusedSuppression {}
+
+impl Rule for UnusedSuppression {
+    fn id(&self) -> &str {
+        UNUSED_SUPPRESSION
+    }
+
+    fn label(&self) -> String {
+        "🔕 SUPPRESSION".to_string()
+    }
+
+    fn description(&self) -> &str {
+        "Suppression comments must silence a problem"
+    }
+
+    fn help(&self) -> &str {
+        r#"Reports `lint-master-disable` comments that silence nothing or name an unknown
+rule. Comments for rules that did not run, e.g. with `--rule`, are left alone."#
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["js", "ts", "tsx", "go"]
+    }
+
+    fn check(
+        &self,
+        _file: &SourceFile,
+        _options: &RuleOptions,
+    ) -> Result<Vec<Diagnostic>, LintError> {
+        Ok(Vec::new())
+    }
+}
@@ -0,0 +1,239 @@
+use crate::constants::{RE_SUPPRESSION, UNUSED_SUPPRESSION};
+use crate::diagnostic::{Diagnostic, Position};
+use regex::Regex;
+
+// one rule, or every rule when None, silenced on lines `first..=last`
+struct Directive {
+    kind: String,
+    rule: Option<String>,
+    position: Position,
+    first: usize,
+    last: usize,
+    used: bool,
+}
+
+// `lint-master-disable-next-line`, `-disable-line` and `-disable` / `-enable` comments
+// of a file, in `//`, `#` or `/* */` style:
+//   // lint-master-disable-next-line console-log, todo -- reason
+pub struct Suppressions {
+    directives: Vec<Directive>,
+}
+
+impl Suppressions {
+    pub fn parse(contents: &str) -> Suppressions {
+        let re = Regex::new(RE_SUPPRESSION).unwrap();
+        let mut directives: Vec<Directive> = Vec::new();
+        // indexes of the `disable` blocks not closed by an `enable` yet
+        let mut open: Vec<usize> = Vec::new();
+
+        for cap in re.captures_iter(contents) {
+            let position = Position::from_offset(contents, cap.get(0).unwrap().start());
+            let kind = &cap[1];
+            // the reason follows `--`, rules are separated by commas or spaces
+            let rules: Vec<Option<String>> = cap
+                .get(2)
+                .map_or("", |text| text.as_str())
+                .split("--")
+                .next()
+                .unwrap_or("")
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|rule| !rule.is_empty())
+                .map(|rule| Some(rule.to_string()))
+                .collect();
+            let rules = if rules.is_empty() { vec![None] } else { rules };
+
+            if kind == "enable" {
+                open.retain(|&i| {
+                    let closes = rules.contains(&None) || rules.contains(&directives[i].rule);
+                    if closes {
+                        directives[i].last = position.line;
+                    }
+                    !closes
+                });
+                continue;
+            }
+
+            let (first, last) = match kind {
+                "disable-next-line" => (position.line + 1, position.line + 1),
+                "disable-line" => (position.line, position.line),
+                _ => (position.line, usize::MAX),
+            };
+            for rule in rules {
+                if kind == "disable" {
+                    open.push(directives.len());
+                }
+                directives.push(Directive {
+                    kind: format!("lint-master-{}", kind),
+                    rule,
+                    position,
+                    first,
+                    last,
+                    used: false,
+                });
+            }
+        }
+
+        Suppressions { directives }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.directives.is_empty()
+    }
+
+    // drops the diagnostics a directive silences, every directive covering one counts
+    // as used
+    pub fn apply(&mut self, diagnostics: &mut Vec<Diagnostic>) {
+        diagnostics.retain(|diagnostic| {
+            let line = diagnostic.start.line;
+            let mut silenced = false;
+            for directive in self.directives.iter_mut() {
+                if (directive.first..=directive.last).contains(&line)
+                    && directive
+                        .rule
+                        .as_ref()
+                        .is_none_or(|rule| *rule == diagnostic.rule_id)
+                {
+                    directive.used = true;
+                    silenced = true;
+                }
+            }
+            !silenced
+        });
+    }
+
+    // directives that silenced nothing; those naming a rule that did not run on the file
+    // are left alone, and those without a rule unless `complete`, every rule of the file ran
+    pub fn unused<F>(
+        &self,
+        path: &str,
+        checked: &[&str],
+        complete: bool,
+        is_known: F,
+    ) -> Vec<Diagnostic>
+    where
+        F: Fn(&str) -> bool,
+    {
+        self.directives
+            .iter()
+            .filter(|directive| !directive.used)
+            .filter_map(|directive| {
+                let message = match &directive.rule {
+                    None if !complete => return None,
+                    None => format!("unused {}", directive.kind),
+                    Some(rule) if !is_known(rule) => {
+                        format!("unknown rule `{}` in {}", rule, directive.kind)
+                    }
+                    Some(rule) if checked.contains(&rule.as_str()) => {
+                        format!("unused {} for `{}`", directive.kind, rule)
+                    }
+                    Some(_) => return None,
+                };
+                Some(
+                    Diagnostic::new(UNUSED_SUPPRESSION, path, message)
+                        .at(directive.position, directive.position)
+                        .with_suggestion("remove the comment".to_string()),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn todo(line: usize) -> Diagnostic {
+        Diagnostic::new("todo", "a.ts", "has unresolved marker".to_string())
+            .at(Position::new(line, 1), Position::new(line, 1))
+    }
+
+    // (rule, first line, last line) of every directive
+    fn ranges(contents: &str) -> Vec<(Option<String>, usize, usize)> {
+        Suppressions::parse(contents)
+            .directives
+            .into_iter()
+            .map(|directive| (directive.rule, directive.first, directive.last))
+            .collect()
+    }
+
+    #[test]
+    fn parse_line_directives() {
+        let contents = "// lint-master-disable-next-line todo, console-log -- legacy\n\
+                        const a = 1 // lint-master-disable-line\n\
+                        # lint-master-disable-line golangci-lint\n";
+        assert_eq!(
+            ranges(contents),
+            vec![
+                (Some("todo".to_string()), 2, 2),
+                (Some("console-log".to_string()), 2, 2),
+                (None, 2, 2),
+                (Some("golangci-lint".to_string()), 3, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_blocks() {
+        let contents = "/* lint-master-disable todo */\n\
+                        // TODO\n\
+                        /* lint-master-enable todo */\n\
+                        // lint-master-disable\n\
+                        // TODO\n";
+        assert_eq!(
+            ranges(contents),
+            vec![(Some("todo".to_string()), 1, 3), (None, 4, usize::MAX)]
+        );
+    }
+
+    #[test]
+    fn enable_without_rule_closes_every_block() {
+        let contents = "// lint-master-disable todo a-rel\n// lint-master-enable\n";
+        assert_eq!(
+            ranges(contents),
+            vec![
+                (Some("todo".to_string()), 1, 2),
+                (Some("a-rel".to_string()), 1, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn apply_marks_every_covering_directive() {
+        let contents = "// lint-master-disable todo\n\
+                        // lint-master-disable-next-line todo\n\
+                        // TODO\n";
+        let mut suppressions = Suppressions::parse(contents);
+        let mut diagnostics = vec![todo(3)];
+        suppressions.apply(&mut diagnostics);
+
+        assert!(diagnostics.is_empty());
+        assert!(suppressions
+            .unused("a.ts", &["todo"], true, |_| true)
+            .is_empty());
+    }
+
+    #[test]
+    fn unused_skips_rule_less_directives_unless_complete() {
+        let suppressions = Suppressions::parse("// lint-master-disable-next-line\nconst a = 1\n");
+
+        assert!(suppressions
+            .unused("a.ts", &["todo"], false, |_| true)
+            .is_empty());
+        let unused = suppressions.unused("a.ts", &["todo"], true, |_| true);
+        assert_eq!(unused.len(), 1);
+        assert_eq!(unused[0].message, "unused lint-master-disable-next-line");
+    }
+
+    #[test]
+    fn unused_reports_unknown_rules_and_skips_rules_not_run() {
+        let contents = "// lint-master-disable-line nope\n// lint-master-disable-line eslint\n";
+        let suppressions = Suppressions::parse(contents);
+        let unused = suppressions.unused("a.ts", &["todo"], true, |id| id != "nope");
+
+        assert_eq!(unused.len(), 1);
+        assert_eq!(
+            unused[0].message,
+            "unknown rule `nope` in lint-master-disable-line"
+        );
+    }
+}
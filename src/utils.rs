@@ -22,37 +22,31 @@ pub fn overflow_text(long_text: &str) -> Vec<String> {
   lines
 }
 
-pub fn status_emoji<'a>(flag: usize) -> &'a str {
-  if flag > 0 {
+pub fn status_emoji<'a>(errors: usize, warnings: usize) -> &'a str {
+  if errors > 0 {
       "🔴 "
+  } else if warnings > 0 {
+      "🟡 "
   } else {
       "✅ "
   }
 }
 
-pub fn status_color(flag: usize) -> Color {
-  if flag > 0 {
+pub fn status_color(errors: usize, warnings: usize) -> Color {
+  if errors > 0 {
       Color::Red
+  } else if warnings > 0 {
+      Color::Yellow
   } else {
       Color::Green
   }
 }
 
-pub fn get_file_name(file_path: &str) -> &str {
-  let file_name = Path::new(file_path).file_name().unwrap().to_str().unwrap();
-  file_name
-}
-
-pub fn get_extension(file_path: &str) -> Result<Option<String>, &'static str> {
-  if let Some(extension) = Path::new(file_path).extension() {
-      if let Some(ext_str) = extension.to_str() {
-          let ext = ext_str.to_lowercase();
-          if ext == "js" || ext == "ts" || ext == "tsx" || ext == "go" {
-              return Ok(Some(ext));
-          }
-      }
-  }
-  Ok(None)
+pub fn get_extension(file_path: &str) -> Option<String> {
+  Path::new(file_path)
+      .extension()
+      .and_then(|extension| extension.to_str())
+      .map(|ext_str| ext_str.to_lowercase())
 }
 
 pub fn convert_to_camel_case(contents: &str) -> String {
@@ -73,4 +67,93 @@ pub fn convert_to_camel_case(contents: &str) -> String {
   }
 
   result
-}
\ No newline at end of file
+}
+// `offset` is inside a JSX opening tag, after `<path ` and before its `>`; the `>` of
+// arrow functions in attribute expressions does not end the tag
+pub fn is_in_jsx_tag(contents: &str, offset: usize) -> bool {
+  let before = &contents[..offset];
+  let close = before
+      .rmatch_indices('>')
+      .map(|(i, _)| i)
+      .find(|&i| !before[..i].ends_with('='));
+
+  match before.rfind('<') {
+      Some(open) if close.is_none_or(|close| close < open) => before[open + 1..]
+          .chars()
+          .next()
+          .is_some_and(|c| c.is_ascii_alphabetic()),
+      _ => false,
+  }
+}
+
+// index of the bracket closing the one at `open`, skipping strings, template literals
+// and comments
+pub fn closing_bracket(bytes: &[u8], open: usize) -> Option<usize> {
+  let mut depth = 0;
+  let mut i = open;
+  while i < bytes.len() {
+      match bytes[i] {
+          b'\'' | b'"' | b'`' => i = string_end(bytes, i)?,
+          b'/' if bytes.get(i + 1) == Some(&b'/') => {
+              i += bytes[i..].iter().position(|&b| b == b'\n')?;
+          }
+          b'/' if bytes.get(i + 1) == Some(&b'*') => {
+              i += 2 + bytes[i + 2..].windows(2).position(|w| w == b"*/")? + 1;
+          }
+          b'(' | b'[' | b'{' => depth += 1,
+          b')' | b']' | b'}' => {
+              depth -= 1;
+              if depth == 0 {
+                  return Some(i);
+              }
+          }
+          _ => {}
+      }
+      i += 1;
+  }
+  None
+}
+
+// index of the quote closing the string at `start`, template substitutions included
+pub fn string_end(bytes: &[u8], start: usize) -> Option<usize> {
+  let quote = bytes[start];
+  let mut i = start + 1;
+  while i < bytes.len() {
+      match bytes[i] {
+          b'\\' => i += 1,
+          b'\n' if quote != b'`' => return None,
+          b'$' if quote == b'`' && bytes.get(i + 1) == Some(&b'{') => {
+              i = closing_bracket(bytes, i + 1)?;
+          }
+          b if b == quote => return Some(i),
+          _ => {}
+      }
+      i += 1;
+  }
+  None
+}
+
+// lowercase hex digits of `bytes`, e.g. of a hash
+pub fn to_hex(bytes: &[u8]) -> String {
+  bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+// escapes text for XML attributes and elements, characters XML 1.0 can not hold are dropped
+pub fn escape_xml(text: &str) -> String {
+  let mut result = String::with_capacity(text.len());
+  for c in text.chars() {
+      match c {
+          '&' => result.push_str("&amp;"),
+          '<' => result.push_str("&lt;"),
+          '>' => result.push_str("&gt;"),
+          '"' => result.push_str("&quot;"),
+          '\'' => result.push_str("&apos;"),
+          '\n' => result.push_str("&#10;"),
+          '\r' => result.push_str("&#13;"),
+          '\t' => result.push_str("&#9;"),
+          c if c < ' ' || c == '\u{FFFE}' || c == '\u{FFFF}' => {}
+          c => result.push(c),
+      }
+  }
+  result
+}